@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use crate::class_index::Import;
+use crate::constants::ConstantExpr;
+
+/// 言語によらない形に変換したアノテーション
+///
+/// 各言語のフロントエンドはアノテーションの名前と引数をそのまま写し取り、
+/// Springのマッピングとしての解釈は共通の処理で行う。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    /// ソースに書かれた名前からパッケージ修飾を除いたもの（例: `GetMapping`）
+    pub name: String,
+    /// 完全修飾名（パッケージ修飾して書かれているか、import宣言から分かる場合のみ）
+    pub qualified_name: Option<String>,
+    /// 引数（名前なしの引数は`value`として扱う）
+    pub args: BTreeMap<String, AnnotationValue>,
+}
+
+/// アノテーションの引数の値
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnnotationValue {
+    /// 単一の式
+    Expr {
+        /// ソースに書かれたままの式
+        text: String,
+        /// 文字列リテラル・定数参照・それらの結合として読める場合の定数式
+        expr: Option<ConstantExpr>,
+    },
+    /// 配列（Javaの`{...}`、Kotlinの`[...]`・`arrayOf(...)`）
+    Array(Vec<AnnotationValue>),
+}
+
+impl Annotation {
+    /// ソースに書かれた名前（`GetMapping`、`org.example.GetMapping`など）から作成する
+    ///
+    /// パッケージ修飾されていない場合は、ファイルのimport宣言から完全修飾名を求める。
+    pub fn new(written_name: &str, imports: &[Import]) -> Self {
+        let written_name: String = written_name
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let (name, qualified_name) = match written_name.rsplit_once('.') {
+            Some((_, name)) => (name.to_string(), Some(written_name.clone())),
+            None => {
+                let qualified_name = imports
+                    .iter()
+                    .find(|import| import.imported_name() == Some(written_name.as_str()))
+                    .map(|import| import.path.clone());
+                (written_name, qualified_name)
+            }
+        };
+
+        Annotation {
+            name,
+            qualified_name,
+            args: BTreeMap::new(),
+        }
+    }
+
+    /// 引数を取得する
+    pub fn arg(&self, key: &str) -> Option<&AnnotationValue> {
+        self.args.get(key)
+    }
+}
+
+impl AnnotationValue {
+    /// 配列の場合は各要素を、それ以外は値そのものを返す
+    pub fn elements(&self) -> Vec<&AnnotationValue> {
+        match self {
+            AnnotationValue::Array(values) => {
+                values.iter().flat_map(AnnotationValue::elements).collect()
+            }
+            value => vec![value],
+        }
+    }
+}
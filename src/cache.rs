@@ -0,0 +1,135 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::class_index::{ClassEntry, ResolutionRecord};
+use crate::constants::ConstantDefinition;
+use crate::diagnostics::Diagnostic;
+use crate::Endpoint;
+
+/// CLIが使うキャッシュディレクトリ（設定ファイルのディレクトリ、またはスキャンするディレクトリからの相対パス）
+pub const DEFAULT_CACHE_DIR: &str = ".path-finder/cache";
+
+// キャッシュの形式を変えたら上げる（形式やpath-finderのバージョン、設定が異なるキャッシュは使わない）
+const CACHE_FORMAT: u32 = 16;
+const CACHE_FILE_NAME: &str = "scan-cache.json";
+
+/// ファイルの内容だけで決まる情報（内容が変わらなければそのまま使える）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileFacts {
+    pub has_request_mapping: bool,
+    pub constants: Vec<ConstantDefinition>,
+    pub classes: Vec<ClassEntry>,
+    /// アノテーション付近の構文エラー
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// ファイルから抽出したエンドポイントと、抽出したときに依存していた他のファイルの情報
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedEndpoints {
+    pub endpoints: Vec<Endpoint>,
+    /// 親クラス・定数参照の解決で見つかった問題（キャッシュを使ったときにも報告する）
+    pub diagnostics: Vec<Diagnostic>,
+    /// 抽出したときの定数表のハッシュ
+    pub constants_hash: String,
+    /// ファイル内のクラスのうち、他のクラスに継承されていたもの（完全修飾名）
+    pub extended_classes: Vec<String>,
+    /// 親クラス・祖先クラスの解決結果
+    pub resolutions: Vec<ResolutionRecord>,
+    /// 解決した親クラス・祖先クラスのファイルとその内容のハッシュ
+    pub dependencies: Vec<(String, String)>,
+}
+
+/// 1ファイル分のキャッシュ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub content_hash: String,
+    pub facts: FileFacts,
+    /// コントローラーを含まないファイル、または未抽出の場合はNone
+    pub endpoints: Option<CachedEndpoints>,
+}
+
+/// ファイルパスごとのキャッシュ（`<cache_dir>/scan-cache.json`に保存する）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScanCache {
+    format: u32,
+    version: String,
+    /// 抽出結果に影響する設定（アノテーションの別名・親クラスの警告の設定）のハッシュ
+    settings_hash: String,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl ScanCache {
+    pub fn new(settings_hash: &str) -> Self {
+        ScanCache {
+            format: CACHE_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            settings_hash: settings_hash.to_string(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// キャッシュを読み込む（存在しない・壊れている・形式や設定が異なる場合は空のキャッシュ）
+    pub fn load(cache_dir: &Path, settings_hash: &str) -> Self {
+        let Ok(content) = fs::read(cache_dir.join(CACHE_FILE_NAME)) else {
+            return ScanCache::new(settings_hash);
+        };
+        match serde_json::from_slice::<ScanCache>(&content) {
+            Ok(cache)
+                if cache.format == CACHE_FORMAT
+                    && cache.version == env!("CARGO_PKG_VERSION")
+                    && cache.settings_hash == settings_hash =>
+            {
+                cache
+            }
+            _ => ScanCache::new(settings_hash),
+        }
+    }
+
+    /// キャッシュを保存する（書き込み途中のファイルを読まないよう、一時ファイルから置き換える）
+    pub fn save(&self, cache_dir: &Path) -> Result<()> {
+        fs::create_dir_all(cache_dir).with_context(|| {
+            format!(
+                "キャッシュディレクトリの作成に失敗しました: {}",
+                cache_dir.display()
+            )
+        })?;
+
+        let cache_file = cache_dir.join(CACHE_FILE_NAME);
+        let temporary_file =
+            cache_dir.join(format!("{}.{}.tmp", CACHE_FILE_NAME, std::process::id()));
+        fs::write(&temporary_file, serde_json::to_vec(self)?).with_context(|| {
+            format!(
+                "キャッシュの書き込みに失敗しました: {}",
+                temporary_file.display()
+            )
+        })?;
+        fs::rename(&temporary_file, &cache_file).with_context(|| {
+            format!(
+                "キャッシュの書き込みに失敗しました: {}",
+                cache_file.display()
+            )
+        })?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, file_path: &str) -> Option<&CacheEntry> {
+        self.entries.get(file_path)
+    }
+
+    pub fn insert(&mut self, file_path: String, entry: CacheEntry) {
+        self.entries.insert(file_path, entry);
+    }
+}
+
+/// ファイルの内容などのハッシュ（SHA-256の16進表記）
+pub fn content_hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
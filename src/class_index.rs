@@ -0,0 +1,379 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::diagnostics::Diagnostic;
+use crate::frontend::FrontendRegistry;
+use crate::source_files::parse_source_files;
+use crate::{Endpoint, Supertype};
+
+/// Java/Kotlinのクラス（インターフェースを含む）の宣言
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassEntry {
+    pub package: Option<String>,
+    /// クラス名（ネストしたクラスは`Outer.Inner`）
+    pub name: String,
+    pub file_path: String,
+    /// 宣言ノードのバイト範囲（ファイルを再パースしたときに宣言ノードを特定するために使う）
+    pub byte_range: (usize, usize),
+    /// インターフェースか（インターフェースで宣言されたエンドポイントは実装クラスのものとして扱う）
+    pub is_interface: bool,
+    /// 継承・実装している型（宣言の順）
+    pub supertypes: Vec<Supertype>,
+    /// 宣言しているファイルのimport宣言（スーパータイプの解決に使う）
+    pub imports: Vec<Import>,
+}
+
+impl ClassEntry {
+    /// 完全修飾名（例: `com.example.Outer.Inner`）
+    pub fn qualified_name(&self) -> String {
+        match &self.package {
+            Some(package) => format!("{}.{}", package, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// パッケージ・外側のクラスを除いたクラス名
+    pub fn simple_name(&self) -> &str {
+        self.name.rsplit('.').next().unwrap_or(&self.name)
+    }
+
+    /// スーパータイプを解決するためのスコープ
+    pub fn scope(&self) -> TypeScope {
+        TypeScope {
+            file_path: self.file_path.clone(),
+            package: self.package.clone(),
+            imports: self.imports.clone(),
+            static_imports: Vec::new(),
+            enclosing_class: self
+                .name
+                .rsplit_once('.')
+                .map(|(outer, _)| outer.to_string()),
+        }
+    }
+}
+
+/// import宣言（`import com.example.Base`、`import com.example.*`、`import com.example.Base as Alias`）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Import {
+    /// インポートしたクラスの完全修飾名（ワイルドカードの場合はパッケージ名）
+    pub path: String,
+    pub wildcard: bool,
+    /// Kotlinの`as`による別名
+    pub alias: Option<String>,
+}
+
+impl Import {
+    /// ファイル内で参照するときの名前（ワイルドカードの場合はNone）
+    pub fn imported_name(&self) -> Option<&str> {
+        if self.wildcard {
+            return None;
+        }
+        self.alias
+            .as_deref()
+            .or_else(|| self.path.rsplit('.').next())
+    }
+}
+
+/// 型名を解決する位置（参照しているファイルとそのpackage・import宣言）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TypeScope {
+    pub file_path: String,
+    pub package: Option<String>,
+    pub imports: Vec<Import>,
+    /// Javaのstatic import宣言（型名の解決には使わず、定数参照の解決に使う）
+    #[serde(default)]
+    pub static_imports: Vec<Import>,
+    /// 参照しているクラスを囲むクラス（ネストしたクラスの場合。例: `Outer`）
+    pub enclosing_class: Option<String>,
+}
+
+/// エンドポイントを宣言しているクラス
+#[derive(Debug, Clone)]
+pub(crate) struct DeclaringClass {
+    /// パッケージ・外側のクラスを除いたクラス名
+    pub name: String,
+    pub package: Option<String>,
+    /// 完全修飾名（例: `com.example.Outer.Inner`）
+    pub qualified_name: String,
+}
+
+impl DeclaringClass {
+    /// クラスを宣言しているスコープ（package宣言・外側のクラス）とクラス名から求める
+    pub fn new(scope: &TypeScope, name: &str) -> Self {
+        let qualified_name = [scope.package.as_deref(), scope.enclosing_class.as_deref()]
+            .into_iter()
+            .flatten()
+            .chain(std::iter::once(name))
+            .collect::<Vec<_>>()
+            .join(".");
+        DeclaringClass {
+            name: name.to_string(),
+            package: scope.package.clone(),
+            qualified_name,
+        }
+    }
+
+    pub fn from_entry(entry: &ClassEntry) -> Self {
+        DeclaringClass {
+            name: entry.simple_name().to_string(),
+            package: entry.package.clone(),
+            qualified_name: entry.qualified_name(),
+        }
+    }
+
+    /// エンドポイントをこのクラスで宣言されたものとして扱う
+    pub fn assign_to(&self, endpoints: &mut [Endpoint]) {
+        for endpoint in endpoints {
+            endpoint.class_name = self.name.clone();
+            endpoint.package = self.package.clone();
+            endpoint.qualified_class_name = self.qualified_name.clone();
+        }
+    }
+}
+
+impl TypeScope {
+    /// インデックスにない型の完全修飾名として考えられるもの
+    ///
+    /// 単一型のimportがあればその完全修飾名だけを返す。それ以外は同じパッケージと
+    /// ワイルドカードのimportから組み立てる（`Outer.Inner`は先頭のクラス名から組み立てる）。
+    pub fn presumed_qualified_names(&self, type_name: &str) -> Vec<String> {
+        let (head, rest) = match type_name.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (type_name, None),
+        };
+        let with_rest = |qualified_head: String| match rest {
+            Some(rest) => format!("{}.{}", qualified_head, rest),
+            None => qualified_head,
+        };
+
+        // パッケージ修飾された名前（パッケージ名は小文字で始まる）
+        if rest.is_some() && head.starts_with(|c: char| c.is_lowercase()) {
+            return vec![type_name.to_string()];
+        }
+
+        if let Some(import) = self
+            .imports
+            .iter()
+            .find(|import| import.imported_name() == Some(head))
+        {
+            return vec![with_rest(import.path.clone())];
+        }
+
+        let same_package = match &self.package {
+            Some(package) => format!("{}.{}", package, head),
+            None => head.to_string(),
+        };
+        std::iter::once(same_package)
+            .chain(
+                self.imports
+                    .iter()
+                    .filter(|import| import.wildcard)
+                    .map(|import| format!("{}.{}", import.path, head)),
+            )
+            .map(with_rest)
+            .collect()
+    }
+}
+
+/// 型名の解決結果
+#[derive(Debug)]
+pub enum Resolution<'a> {
+    Found(&'a ClassEntry),
+    NotFound,
+    /// 候補が複数あり一意に決められない
+    Ambiguous(Vec<&'a ClassEntry>),
+}
+
+impl Resolution<'_> {
+    /// 解決の候補（ファイルパス, 完全修飾名）。見つからない場合は空、曖昧な場合は複数
+    pub fn candidates(&self) -> Vec<(String, String)> {
+        let entries = match self {
+            Resolution::Found(entry) => vec![*entry],
+            Resolution::NotFound => Vec::new(),
+            Resolution::Ambiguous(entries) => entries.clone(),
+        };
+        entries
+            .into_iter()
+            .map(|entry| (entry.file_path.clone(), entry.qualified_name()))
+            .collect()
+    }
+}
+
+/// 親クラスの解決の記録（キャッシュしたエンドポイントがまだ使えるかの確認に使う）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolutionRecord {
+    pub type_name: String,
+    pub scope: TypeScope,
+    pub candidates: Vec<(String, String)>,
+}
+
+impl ResolutionRecord {
+    /// 現在のインデックスで解決し直しても同じ結果になるか
+    pub fn still_holds(&self, class_index: &ClassIndex) -> bool {
+        class_index
+            .resolve(&self.type_name, &self.scope)
+            .candidates()
+            == self.candidates
+    }
+}
+
+/// スキャン対象のディレクトリから一度だけ構築するクラスのインデックス
+#[derive(Debug, Default)]
+pub struct ClassIndex {
+    entries: Vec<ClassEntry>,
+    by_qualified_name: HashMap<String, Vec<usize>>,
+}
+
+impl ClassIndex {
+    /// ディレクトリ内のJava/Kotlinファイルからクラス宣言を収集する
+    ///
+    /// 読み込みや解析に失敗したファイルはスキップし、`diagnostics`に追加する
+    pub fn from_directory(dir_path: &str, diagnostics: &mut Vec<Diagnostic>) -> Self {
+        let entries_per_file = parse_source_files(
+            dir_path,
+            &FrontendRegistry::default(),
+            diagnostics,
+            |frontend, file_path, source_code| frontend.discover_classes(file_path, source_code),
+        );
+
+        let mut index = ClassIndex::default();
+        for entries in entries_per_file {
+            index.extend(entries);
+        }
+        index
+    }
+
+    pub fn extend(&mut self, entries: Vec<ClassEntry>) {
+        for entry in entries {
+            let position = self.entries.len();
+            self.by_qualified_name
+                .entry(entry.qualified_name())
+                .or_default()
+                .push(position);
+            self.entries.push(entry);
+        }
+    }
+
+    /// スーパータイプなどの型名を、参照している位置のpackage・import宣言に従って解決する
+    ///
+    /// 解決の順序は外側のクラスのメンバー → 単一型のimport（Kotlinの別名を含む） → 同じパッケージ
+    /// → ワイルドカードのimport。どれにも該当しない場合は、同名のクラスがあっても推測せずに見つからないものとする。
+    /// `Outer.Inner`のような名前は、完全修飾名として見つからなければ先頭のクラス名から解決する。
+    pub fn resolve(&self, type_name: &str, scope: &TypeScope) -> Resolution<'_> {
+        if let Some((head, rest)) = type_name.split_once('.') {
+            // パッケージ修飾された名前
+            match self.resolve_qualified(type_name, scope) {
+                Resolution::NotFound => {}
+                resolution => return resolution,
+            }
+
+            // 外側のクラス名から始まる名前
+            return match self.resolve(head, scope) {
+                Resolution::Found(outer) => {
+                    self.resolve_qualified(&format!("{}.{}", outer.qualified_name(), rest), scope)
+                }
+                resolution => resolution,
+            };
+        }
+
+        // 外側のクラスのメンバー（内側のクラスから順に探す）
+        let mut enclosing_class = scope.enclosing_class.as_deref();
+        while let Some(outer) = enclosing_class {
+            let member = match &scope.package {
+                Some(package) => format!("{}.{}.{}", package, outer, type_name),
+                None => format!("{}.{}", outer, type_name),
+            };
+            match self.resolve_qualified(&member, scope) {
+                Resolution::NotFound => {}
+                resolution => return resolution,
+            }
+            enclosing_class = outer.rsplit_once('.').map(|(outer, _)| outer);
+        }
+
+        // 単一型のimport（インデックスにないクラスをimportしている場合は、同名の別クラスを探さない）
+        if let Some(import) = scope
+            .imports
+            .iter()
+            .find(|import| import.imported_name() == Some(type_name))
+        {
+            return self.resolve_qualified(&import.path, scope);
+        }
+
+        // 同じパッケージ（パッケージ宣言がない場合はデフォルトパッケージ）
+        let same_package = match &scope.package {
+            Some(package) => format!("{}.{}", package, type_name),
+            None => type_name.to_string(),
+        };
+        match self.resolve_qualified(&same_package, scope) {
+            Resolution::NotFound => {}
+            resolution => return resolution,
+        }
+
+        // ワイルドカードのimport
+        let mut candidates = Vec::new();
+        for import in scope.imports.iter().filter(|import| import.wildcard) {
+            match self.resolve_qualified(&format!("{}.{}", import.path, type_name), scope) {
+                Resolution::Found(entry) => candidates.push(entry),
+                Resolution::Ambiguous(entries) => candidates.extend(entries),
+                Resolution::NotFound => {}
+            }
+        }
+        Self::single_or_ambiguous(candidates)
+    }
+
+    // 完全修飾名で解決する（JavaとKotlinに同じ完全修飾名がある場合は参照元と同じ言語を優先する）
+    fn resolve_qualified(&self, qualified_name: &str, scope: &TypeScope) -> Resolution<'_> {
+        let candidates: Vec<&ClassEntry> = self
+            .by_qualified_name
+            .get(qualified_name)
+            .map(|positions| {
+                positions
+                    .iter()
+                    .map(|&position| &self.entries[position])
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if candidates.len() > 1 {
+            let extension = Path::new(&scope.file_path).extension();
+            let same_language: Vec<&ClassEntry> = candidates
+                .iter()
+                .copied()
+                .filter(|candidate| Path::new(&candidate.file_path).extension() == extension)
+                .collect();
+            if same_language.len() == 1 {
+                return Resolution::Found(same_language[0]);
+            }
+        }
+
+        Self::single_or_ambiguous(candidates)
+    }
+
+    fn single_or_ambiguous(candidates: Vec<&ClassEntry>) -> Resolution<'_> {
+        match candidates.len() {
+            0 => Resolution::NotFound,
+            1 => Resolution::Found(candidates[0]),
+            _ => Resolution::Ambiguous(candidates),
+        }
+    }
+
+    /// 他のクラスに継承・実装されているクラスの完全修飾名
+    ///
+    /// スーパータイプは継承しているクラスのpackage・import宣言に従って解決する（別パッケージの同名クラスは含めない）。
+    /// 一意に解決できないスーパータイプは継承しないため、どのクラスも継承されているものとしない。
+    pub fn extended_class_names(&self) -> HashSet<String> {
+        self.entries
+            .iter()
+            .flat_map(|entry| {
+                let scope = entry.scope();
+                entry.supertypes.iter().filter_map(move |supertype| {
+                    match self.resolve(&supertype.name, &scope) {
+                        Resolution::Found(parent) => Some(parent.qualified_name()),
+                        _ => None,
+                    }
+                })
+            })
+            .collect()
+    }
+}
@@ -1,7 +0,0 @@
-pub mod types;
-pub mod warnings;
-pub mod inheritance;
-pub mod file_utils;
-
-// 便利な再エクスポート
-pub use types::{Endpoint, Parameter, InheritanceTask, ScanResult};
\ No newline at end of file
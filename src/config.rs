@@ -0,0 +1,141 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::missing_parent::MissingParentPolicy;
+use crate::ScanOptions;
+
+/// スキャンするディレクトリか、その親ディレクトリから自動で読み込む設定ファイルの名前
+pub const CONFIG_FILE_NAME: &str = "path-finder.toml";
+
+/// 設定ファイル（TOML）の内容
+///
+/// ```toml
+/// roots = ["src/main/java", "src/main/kotlin"]
+/// exclude = ["**/generated"]
+/// gitignore = true
+/// context_path = "/api"
+/// format = "json"
+///
+/// [mapping_annotations]
+/// ApiGet = "GetMapping"
+///
+/// [missing_parents]
+/// suppress = ["org.springframework.*", "*Dto"]
+/// warn = ["BaseController"]
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// スキャンするディレクトリ（設定ファイルのディレクトリからの相対パス）
+    pub roots: Vec<String>,
+    /// スキャンするファイル・除外するファイル（スキャンするディレクトリからの相対パスのglob）
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    /// ビルドの出力先やツールのディレクトリを除外するか
+    pub default_excludes: bool,
+    /// .gitignoreで無視されているファイルを除外するか
+    pub gitignore: bool,
+    /// マッピングアノテーションの別名（別名 → Springのアノテーション名）
+    pub mapping_annotations: BTreeMap<String, String>,
+    /// すべてのエンドポイントのパスの前に付けるパス
+    pub context_path: Option<String>,
+    /// 出力形式の既定値
+    pub format: Option<OutputFormat>,
+    /// 見つからない親クラスの警告の設定
+    pub missing_parents: MissingParentPolicy,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            roots: Vec::new(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            default_excludes: true,
+            gitignore: false,
+            mapping_annotations: BTreeMap::new(),
+            context_path: None,
+            format: None,
+            missing_parents: MissingParentPolicy::default(),
+        }
+    }
+}
+
+/// エンドポイントの出力形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => bail!("出力形式はtextまたはjsonを指定してください: {}", s),
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("設定ファイルの読み込みに失敗しました: {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("設定ファイルの形式が不正です: {}", path.display()))
+    }
+
+    /// `start_dir`から親ディレクトリへ順に`path-finder.toml`を探して読み込む
+    ///
+    /// 見つかった場合は設定ファイルのパスと内容を返す。
+    pub fn discover(start_dir: &Path) -> Result<Option<(PathBuf, Config)>> {
+        let start_dir = start_dir
+            .canonicalize()
+            .with_context(|| format!("ディレクトリが見つかりません: {}", start_dir.display()))?;
+
+        for dir in start_dir.ancestors() {
+            let path = dir.join(CONFIG_FILE_NAME);
+            if path.is_file() {
+                let config = Config::load(&path)?;
+                return Ok(Some((path, config)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// スキャンするディレクトリ（`config_dir`は設定ファイルのディレクトリ）
+    ///
+    /// `roots`が指定されていない場合は設定ファイルのディレクトリをスキャンする。
+    pub fn root_paths(&self, config_dir: &Path) -> Vec<String> {
+        if self.roots.is_empty() {
+            return vec![config_dir.to_string_lossy().to_string()];
+        }
+        self.roots
+            .iter()
+            .map(|root| config_dir.join(root).to_string_lossy().to_string())
+            .collect()
+    }
+
+    /// スキャンの設定に変換する（並列数・キャッシュはCLIで指定する）
+    pub fn scan_options(&self) -> ScanOptions {
+        ScanOptions {
+            missing_parents: self.missing_parents.clone(),
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+            default_excludes: self.default_excludes,
+            gitignore: self.gitignore,
+            mapping_annotations: self.mapping_annotations.clone(),
+            context_path: self.context_path.clone(),
+            ..ScanOptions::default()
+        }
+    }
+}
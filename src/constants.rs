@@ -0,0 +1,357 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::class_index::{Import, TypeScope};
+use crate::diagnostics::Diagnostic;
+use crate::frontend::FrontendRegistry;
+use crate::source_files::parse_source_files;
+
+// 定数参照の解決を打ち切る深さ（循環参照対策）
+const MAX_RESOLVE_DEPTH: usize = 32;
+
+// Springの`org.springframework.http.MediaType`の文字列定数（スキャン対象外のため組み込みで持つ）
+const MEDIA_TYPE_CONSTANTS: &[(&str, &str)] = &[
+    ("ALL_VALUE", "*/*"),
+    ("APPLICATION_ATOM_XML_VALUE", "application/atom+xml"),
+    ("APPLICATION_CBOR_VALUE", "application/cbor"),
+    (
+        "APPLICATION_FORM_URLENCODED_VALUE",
+        "application/x-www-form-urlencoded",
+    ),
+    (
+        "APPLICATION_GRAPHQL_RESPONSE_VALUE",
+        "application/graphql-response+json",
+    ),
+    ("APPLICATION_JSON_VALUE", "application/json"),
+    (
+        "APPLICATION_JSON_UTF8_VALUE",
+        "application/json;charset=UTF-8",
+    ),
+    ("APPLICATION_NDJSON_VALUE", "application/x-ndjson"),
+    ("APPLICATION_OCTET_STREAM_VALUE", "application/octet-stream"),
+    ("APPLICATION_PDF_VALUE", "application/pdf"),
+    ("APPLICATION_PROBLEM_JSON_VALUE", "application/problem+json"),
+    ("APPLICATION_PROBLEM_XML_VALUE", "application/problem+xml"),
+    ("APPLICATION_PROTOBUF_VALUE", "application/x-protobuf"),
+    ("APPLICATION_RSS_XML_VALUE", "application/rss+xml"),
+    ("APPLICATION_STREAM_JSON_VALUE", "application/stream+json"),
+    ("APPLICATION_XHTML_XML_VALUE", "application/xhtml+xml"),
+    ("APPLICATION_XML_VALUE", "application/xml"),
+    ("IMAGE_GIF_VALUE", "image/gif"),
+    ("IMAGE_JPEG_VALUE", "image/jpeg"),
+    ("IMAGE_PNG_VALUE", "image/png"),
+    ("MULTIPART_FORM_DATA_VALUE", "multipart/form-data"),
+    ("MULTIPART_MIXED_VALUE", "multipart/mixed"),
+    ("MULTIPART_RELATED_VALUE", "multipart/related"),
+    ("TEXT_EVENT_STREAM_VALUE", "text/event-stream"),
+    ("TEXT_HTML_VALUE", "text/html"),
+    ("TEXT_MARKDOWN_VALUE", "text/markdown"),
+    ("TEXT_PLAIN_VALUE", "text/plain"),
+    ("TEXT_XML_VALUE", "text/xml"),
+];
+
+/// アノテーション引数や定数定義に現れる文字列式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConstantExpr {
+    /// 文字列リテラル
+    Literal(String),
+    /// 定数参照（`NAME`、`ApiPaths.NAME`、`com.example.ApiPaths.NAME`など）
+    Reference(String),
+    /// 文字列結合（`+`や文字列テンプレート）
+    Concat(Vec<ConstantExpr>),
+}
+
+/// Javaの`static final String`フィールド、Kotlinの`const val`の定義
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstantDefinition {
+    pub package: Option<String>,
+    /// 定義しているクラス名（ネストしたクラスは`Outer.Inner`、Kotlinのトップレベル定数はNone）
+    pub owner: Option<String>,
+    pub name: String,
+    pub expr: ConstantExpr,
+    /// 定義しているファイルのimport宣言（Javaのstatic importを含む。式の中の定数参照の解決に使う）
+    #[serde(default)]
+    pub imports: Vec<Import>,
+}
+
+impl ConstantDefinition {
+    /// 完全修飾名（例: `com.example.ApiPaths.BASE`）
+    pub fn qualified_name(&self) -> String {
+        [
+            self.package.as_deref(),
+            self.owner.as_deref(),
+            Some(self.name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(".")
+    }
+
+    // 定義の式を解決するスコープ
+    fn scope(&self) -> ConstantScope {
+        ConstantScope {
+            package: self.package.clone(),
+            owner: self.owner.clone(),
+            imports: self.imports.clone(),
+        }
+    }
+}
+
+/// 定数参照を解決する位置（参照が書かれているクラスとファイルのpackage・import宣言）
+#[derive(Debug, Clone, Default)]
+pub struct ConstantScope {
+    pub package: Option<String>,
+    /// 参照が書かれているクラス（ネストしたクラスは`Outer.Inner`、Kotlinのトップレベルの場合はNone）
+    pub owner: Option<String>,
+    /// import宣言（Javaのstatic importを含む）
+    pub imports: Vec<Import>,
+}
+
+impl ConstantScope {
+    /// クラスに書かれた定数参照を解決するスコープ
+    pub fn for_class(scope: &TypeScope, class_name: &str) -> Self {
+        ConstantScope {
+            package: scope.package.clone(),
+            owner: Some(match &scope.enclosing_class {
+                Some(outer) => format!("{}.{}", outer, class_name),
+                None => class_name.to_string(),
+            }),
+            imports: scope
+                .imports
+                .iter()
+                .chain(&scope.static_imports)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    // 修飾なしの名前（`NAME`・`ApiPaths`・`ApiPaths.Admin`）を、参照先として考えられる完全修飾名に展開する
+    //
+    // 優先順に並べたグループを返す（同じグループの候補が複数見つかった場合は曖昧とする）。
+    // 順序は外側のクラスのメンバー → 単一のimport（Kotlinの別名を含む） → 同じパッケージ → ワイルドカードのimport。
+    // 単一のimportが該当した場合は、見つからなくても同名の別の定義を探さない。
+    fn qualified_candidates(&self, name: &str) -> Vec<Vec<String>> {
+        let (head, rest) = match name.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (name, None),
+        };
+        let with_rest = |qualified_head: String| match rest {
+            Some(rest) => format!("{}.{}", qualified_head, rest),
+            None => qualified_head,
+        };
+        let in_package = |name: &str| match &self.package {
+            Some(package) => format!("{}.{}", package, name),
+            None => name.to_string(),
+        };
+
+        let mut groups = Vec::new();
+
+        // パッケージ修飾された名前（パッケージ名は小文字で始まる）
+        if rest.is_some() && head.starts_with(|c: char| c.is_lowercase()) {
+            groups.push(vec![name.to_string()]);
+        }
+
+        // 参照が書かれているクラスと外側のクラスのメンバー（内側のクラスから順に探す）
+        let mut owner = self.owner.as_deref();
+        while let Some(class_name) = owner {
+            groups.push(vec![in_package(&format!("{}.{}", class_name, name))]);
+            owner = class_name.rsplit_once('.').map(|(outer, _)| outer);
+        }
+
+        if let Some(import) = self
+            .imports
+            .iter()
+            .find(|import| import.imported_name() == Some(head))
+        {
+            groups.push(vec![with_rest(import.path.clone())]);
+            return groups;
+        }
+
+        groups.push(vec![in_package(name)]);
+        groups.push(
+            self.imports
+                .iter()
+                .filter(|import| import.wildcard)
+                .map(|import| format!("{}.{}", import.path, name))
+                .collect(),
+        );
+
+        groups
+    }
+}
+
+/// 解決できなかった定数参照
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnresolvedConstant {
+    /// 定義が見つからない（参照式）
+    NotFound(String),
+    /// 候補が複数あり一意に決められない（参照式, 候補の完全修飾名）
+    Ambiguous(String, Vec<String>),
+}
+
+impl fmt::Display for UnresolvedConstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnresolvedConstant::NotFound(reference) => {
+                write!(f, "Constant {} could not be resolved", reference)
+            }
+            UnresolvedConstant::Ambiguous(reference, candidates) => write!(
+                f,
+                "Constant {} is ambiguous: {}",
+                reference,
+                candidates.join(", ")
+            ),
+        }
+    }
+}
+
+/// スキャン対象のディレクトリから集めた定数表
+#[derive(Debug, Default)]
+pub struct ConstantTable {
+    definitions: HashMap<String, Vec<ConstantDefinition>>,
+}
+
+impl ConstantTable {
+    /// Springの組み込み定数（`MediaType.APPLICATION_JSON_VALUE`など）のみを持つ定数表
+    pub fn with_builtins() -> Self {
+        let mut table = ConstantTable::default();
+        table.extend(
+            MEDIA_TYPE_CONSTANTS
+                .iter()
+                .map(|(name, value)| ConstantDefinition {
+                    package: Some("org.springframework.http".to_string()),
+                    owner: Some("MediaType".to_string()),
+                    name: name.to_string(),
+                    expr: ConstantExpr::Literal(value.to_string()),
+                    imports: Vec::new(),
+                })
+                .collect(),
+        );
+        table
+    }
+
+    /// ディレクトリ内のJava/Kotlinファイルから定数定義を収集する（組み込み定数を含む）
+    ///
+    /// 読み込みや解析に失敗したファイルはスキップし、`diagnostics`に追加する
+    pub fn from_directory(dir_path: &str, diagnostics: &mut Vec<Diagnostic>) -> Self {
+        let definitions_per_file = parse_source_files(
+            dir_path,
+            &FrontendRegistry::default(),
+            diagnostics,
+            |frontend, file_path, source_code| frontend.extract_constants(file_path, source_code),
+        );
+
+        let mut table = ConstantTable::with_builtins();
+        for definitions in definitions_per_file {
+            table.extend(definitions);
+        }
+        table
+    }
+
+    pub fn extend(&mut self, definitions: Vec<ConstantDefinition>) {
+        for definition in definitions {
+            self.definitions
+                .entry(definition.name.clone())
+                .or_default()
+                .push(definition);
+        }
+    }
+
+    /// 式を文字列に解決する。定数参照は`scope`のpackage・import宣言に従って解決する
+    pub fn resolve(
+        &self,
+        expr: &ConstantExpr,
+        scope: &ConstantScope,
+    ) -> Result<String, UnresolvedConstant> {
+        self.resolve_with_depth(expr, scope, 0)
+    }
+
+    fn resolve_with_depth(
+        &self,
+        expr: &ConstantExpr,
+        scope: &ConstantScope,
+        depth: usize,
+    ) -> Result<String, UnresolvedConstant> {
+        match expr {
+            ConstantExpr::Literal(value) => Ok(value.clone()),
+            ConstantExpr::Concat(parts) => parts
+                .iter()
+                .map(|part| self.resolve_with_depth(part, scope, depth + 1))
+                .collect(),
+            ConstantExpr::Reference(reference) => {
+                if depth > MAX_RESOLVE_DEPTH {
+                    return Err(UnresolvedConstant::NotFound(reference.clone()));
+                }
+                let definition = self.find_definition(reference, scope)?;
+                self.resolve_with_depth(&definition.expr, &definition.scope(), depth + 1)
+            }
+        }
+    }
+
+    fn find_definition(
+        &self,
+        reference: &str,
+        scope: &ConstantScope,
+    ) -> Result<&ConstantDefinition, UnresolvedConstant> {
+        for group in scope.qualified_candidates(reference) {
+            let found: Vec<&ConstantDefinition> = group
+                .iter()
+                .flat_map(|qualified_name| {
+                    let name = qualified_name.rsplit('.').next().unwrap_or(qualified_name);
+                    self.definitions
+                        .get(name)
+                        .into_iter()
+                        .flatten()
+                        .filter(move |definition| &definition.qualified_name() == qualified_name)
+                })
+                .collect();
+
+            match found.as_slice() {
+                [] => {}
+                [definition] => return Ok(definition),
+                _ => {
+                    return Err(UnresolvedConstant::Ambiguous(
+                        reference.to_string(),
+                        found
+                            .iter()
+                            .map(|definition| definition.qualified_name())
+                            .collect(),
+                    ))
+                }
+            }
+        }
+
+        Err(UnresolvedConstant::NotFound(reference.to_string()))
+    }
+}
+
+// 文字列リテラルのエスケープシーケンス（`\"`・`\n`・`\u0041`・`\101`など）を文字に変換する
+// 変換できないエスケープシーケンスはそのまま返す
+pub(crate) fn unescape(escape_sequence: &str) -> String {
+    let Some(body) = escape_sequence.strip_prefix('\\') else {
+        return escape_sequence.to_string();
+    };
+
+    let unescaped = match body {
+        "n" => Some('\n'),
+        "t" => Some('\t'),
+        "r" => Some('\r'),
+        "b" => Some('\u{8}'),
+        "f" => Some('\u{c}'),
+        "s" => Some(' '),
+        "\"" | "'" | "\\" | "$" => body.chars().next(),
+        // Unicodeエスケープ（Javaでは`\uuuu0041`のようにuを重ねられる）
+        _ if body.starts_with('u') => u32::from_str_radix(body.trim_start_matches('u'), 16)
+            .ok()
+            .and_then(char::from_u32),
+        // 8進数のエスケープ（Java）
+        _ => u32::from_str_radix(body, 8).ok().and_then(char::from_u32),
+    };
+
+    unescaped
+        .map(String::from)
+        .unwrap_or_else(|| escape_sequence.to_string())
+}
@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// 構文エラーの前後を含めて表示する最大の文字数
+const MAX_SNIPPET_LENGTH: usize = 60;
+
+// アノテーションを構成するノードの種類（Java・Kotlin）
+const ANNOTATION_NODE_KINDS: &[&str] = &[
+    "annotation",
+    "marker_annotation",
+    "annotation_argument_list",
+    "element_value_pair",
+    "element_value_array_initializer",
+    "modifiers",
+    "file_annotation",
+];
+
+/// スキャン中に見つかった問題（スキャンは中断せずに続ける）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub kind: DiagnosticKind,
+    pub file_path: String,
+    /// 問題のあったクラス（親クラスの解決に関する問題の場合は継承しているクラス）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    /// 行番号（1始まり。ファイル全体に関する問題の場合はNone）
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// ファイルを解析できず、そのファイルのエンドポイントを出力できない
+    Error,
+    /// 解析は続けたが、結果が不完全な可能性がある
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// ファイルを読み込めない（権限がない、UTF-8でないなど）
+    ReadError,
+    /// 構文解析・抽出に失敗した
+    ParseError,
+    /// アノテーション付近の構文エラー（tree-sitterのERROR/MISSINGノード）
+    SyntaxError,
+    /// 親クラスが見つからない
+    UnresolvedParent,
+    /// 親クラスの候補が複数あり一意に決められない
+    AmbiguousParent,
+    /// 親クラスからのエンドポイントの抽出に失敗した
+    InheritanceError,
+    /// アノテーション引数の定数参照を解決できない（見つからない、または候補が複数ある）
+    UnresolvedConstant,
+    /// `@RequestMapping`の`method`属性にRequestMethodの定数以外が指定されている（無視する）
+    UnsupportedRequestMethod,
+    /// スキャン結果のキャッシュを保存できない
+    CacheError,
+    /// シンボリックリンクが祖先のディレクトリを指している（循環しているため辿らない）
+    SymlinkCycle,
+}
+
+impl Diagnostic {
+    pub fn error(kind: DiagnosticKind, file_path: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            kind,
+            file_path: file_path.to_string(),
+            class_name: None,
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(kind: DiagnosticKind, file_path: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            kind,
+            file_path: file_path.to_string(),
+            class_name: None,
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> Self {
+        Diagnostic {
+            line: Some(line),
+            ..self
+        }
+    }
+
+    pub fn for_class(self, class_name: &str) -> Self {
+        Diagnostic {
+            class_name: Some(class_name.to_string()),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(
+                f,
+                "{}: {}:{}: {}",
+                self.severity, self.file_path, line, self.message
+            ),
+            None => write!(f, "{}: {}: {}", self.severity, self.file_path, self.message),
+        }
+    }
+}
+
+/// 診断結果の件数を「2 errors, 1 warning」の形式で返す（診断結果がない場合はNone）
+pub fn summarize(diagnostics: &[Diagnostic]) -> Option<String> {
+    if diagnostics.is_empty() {
+        return None;
+    }
+
+    let count = |severity| {
+        diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    };
+    let plural = |count: usize, word: &str| {
+        if count == 1 {
+            format!("{} {}", count, word)
+        } else {
+            format!("{} {}s", count, word)
+        }
+    };
+
+    Some(format!(
+        "{}, {}",
+        plural(count(Severity::Error), "error"),
+        plural(count(Severity::Warning), "warning")
+    ))
+}
+
+/// アノテーション付近の構文エラー（ERROR/MISSINGノード）を列挙する
+///
+/// アノテーションの構文エラーはtree-sitterがエラー回復するため解析は続けられるが、
+/// エンドポイントの抽出漏れにつながるため報告する。
+pub(crate) fn syntax_errors_near_annotations(
+    file_path: &str,
+    source_code: &str,
+    root_node: tree_sitter::Node,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    if !root_node.has_error() {
+        return diagnostics;
+    }
+
+    let mut pending = vec![root_node];
+    while let Some(node) = pending.pop() {
+        if node.is_error() || node.is_missing() {
+            if is_near_annotation(source_code, node) {
+                let line = node.start_position().row + 1;
+                let message = if node.is_missing() {
+                    format!("Missing `{}` near annotation", node.kind())
+                } else {
+                    format!(
+                        "Syntax error near annotation: `{}`",
+                        snippet(&source_code[node.byte_range()])
+                    )
+                };
+                diagnostics.push(
+                    Diagnostic::warning(DiagnosticKind::SyntaxError, file_path, message)
+                        .at_line(line),
+                );
+            }
+            // ERRORノードの内側は報告しない
+            continue;
+        }
+
+        if node.has_error() {
+            let mut cursor = node.walk();
+            let mut children: Vec<_> = node.children(&mut cursor).collect();
+            children.reverse();
+            pending.extend(children);
+        }
+    }
+
+    diagnostics
+}
+
+// エラーノードがアノテーションの一部、またはアノテーションを含んでいるか
+fn is_near_annotation(source_code: &str, node: tree_sitter::Node) -> bool {
+    if source_code[node.byte_range()].contains('@') {
+        return true;
+    }
+
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if ANNOTATION_NODE_KINDS.contains(&parent.kind()) {
+            return true;
+        }
+        current = parent.parent();
+    }
+
+    [node.prev_sibling(), node.next_sibling()]
+        .into_iter()
+        .flatten()
+        .any(|sibling| ANNOTATION_NODE_KINDS.contains(&sibling.kind()))
+}
+
+// エラー箇所のテキストを1行に収まるように切り詰める
+fn snippet(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() > MAX_SNIPPET_LENGTH {
+        format!(
+            "{}...",
+            text.chars().take(MAX_SNIPPET_LENGTH).collect::<String>()
+        )
+    } else {
+        text
+    }
+}
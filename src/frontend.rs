@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::annotation::Annotation;
+use crate::cache::FileFacts;
+use crate::class_index::{ClassEntry, TypeScope};
+use crate::constants::ConstantDefinition;
+use crate::diagnostics::Diagnostic;
+use crate::mapping_annotations::MappingAnnotations;
+use crate::parsers::java::JavaFrontend;
+use crate::parsers::kotlin::KotlinFrontend;
+use crate::spring;
+use crate::Supertype;
+
+/// 1つの言語のソースファイルを読み取るフロントエンド
+///
+/// 言語を追加する場合はこのトレイトを実装し、[`FrontendRegistry::register`]で登録する。
+/// フロントエンドはクラス宣言とアノテーションを言語によらないモデル（[`TypeDeclaration`]）に変換するだけで、
+/// コントローラーの判定・パスの結合などのSpringのマッピングとしての解釈や、
+/// ファイルの列挙・キャッシュ・親クラスの解決は、言語によらず共通の処理で行う。
+pub trait LanguageFrontend: Send + Sync {
+    /// 言語の名前（例: `Java`）
+    fn name(&self) -> &str;
+
+    /// 対象とするファイルの拡張子（`.`は含まない）
+    fn extensions(&self) -> &[&str];
+
+    /// ファイル内のクラス・インターフェースの宣言を、付与されたアノテーションとともに抽出する
+    ///
+    /// ネストしたクラスも1つの宣言として返す（各宣言のメソッドは直接のメンバーのみ）。
+    fn extract_types(&self, file_path: &str, source_code: &str) -> Result<Vec<TypeDeclaration>>;
+
+    /// ファイル内の定数定義を抽出する（アノテーション引数の定数参照の解決に使う）
+    fn extract_constants(
+        &self,
+        file_path: &str,
+        source_code: &str,
+    ) -> Result<Vec<ConstantDefinition>>;
+
+    /// アノテーション付近の構文エラーを診断結果として返す（既定では返さない）
+    fn syntax_diagnostics(&self, _file_path: &str, _source_code: &str) -> Vec<Diagnostic> {
+        Vec::new()
+    }
+
+    /// ファイル内のクラス・インターフェースの宣言をクラスインデックス用に抽出する
+    fn discover_classes(&self, file_path: &str, source_code: &str) -> Result<Vec<ClassEntry>> {
+        Ok(self
+            .extract_types(file_path, source_code)?
+            .iter()
+            .map(TypeDeclaration::class_entry)
+            .collect())
+    }
+
+    /// キャッシュの単位となる、ファイル単独で決まる情報をまとめて抽出する
+    ///
+    /// 既定の実装は上のメソッドを順に呼び出す。1回のパースでまとめて抽出できる場合は上書きする。
+    fn extract_file_facts(
+        &self,
+        file_path: &str,
+        source_code: &str,
+        annotations: &MappingAnnotations,
+    ) -> Result<FileFacts> {
+        let types = self.extract_types(file_path, source_code)?;
+        Ok(FileFacts {
+            has_request_mapping: spring::contains_controller(&types, annotations),
+            constants: self.extract_constants(file_path, source_code)?,
+            classes: types.iter().map(TypeDeclaration::class_entry).collect(),
+            diagnostics: self.syntax_diagnostics(file_path, source_code),
+        })
+    }
+}
+
+/// フロントエンドがファイル内で見つけたクラス・インターフェースの宣言
+#[derive(Debug, Clone)]
+pub struct TypeDeclaration {
+    /// クラス名（パッケージ・外側のクラスを除く）
+    pub name: String,
+    /// クラスを宣言しているスコープ（ファイル・package・import宣言と外側のクラス）
+    pub scope: TypeScope,
+    /// 宣言ノードのバイト範囲（親クラスとして再度読み込んだときに宣言を特定するために使う）
+    pub byte_range: (usize, usize),
+    pub is_interface: bool,
+    /// 継承・実装している型（宣言の順）
+    pub supertypes: Vec<Supertype>,
+    /// クラスに付与されたアノテーション
+    pub annotations: Vec<Annotation>,
+    /// クラスで宣言されたメソッド（ネストしたクラスのメソッドは含まない）
+    pub methods: Vec<MethodDeclaration>,
+}
+
+/// メソッドの宣言
+#[derive(Debug, Clone)]
+pub struct MethodDeclaration {
+    pub name: String,
+    /// 宣言の開始行と終了行（1始まり）
+    pub line_range: (usize, usize),
+    pub annotations: Vec<Annotation>,
+    pub parameters: Vec<ParameterDeclaration>,
+}
+
+/// メソッドの引数の宣言
+#[derive(Debug, Clone)]
+pub struct ParameterDeclaration {
+    pub name: String,
+    /// ソースに書かれたままの型
+    pub param_type: String,
+    pub annotations: Vec<Annotation>,
+}
+
+impl TypeDeclaration {
+    /// クラスインデックスに登録する宣言
+    pub fn class_entry(&self) -> ClassEntry {
+        ClassEntry {
+            package: self.scope.package.clone(),
+            // ネストしたクラスは外側のクラス名で修飾する
+            name: match &self.scope.enclosing_class {
+                Some(outer) => format!("{}.{}", outer, self.name),
+                None => self.name.clone(),
+            },
+            file_path: self.scope.file_path.clone(),
+            byte_range: self.byte_range,
+            is_interface: self.is_interface,
+            supertypes: self.supertypes.clone(),
+            imports: self.scope.imports.clone(),
+        }
+    }
+}
+
+/// スキャンに使うフロントエンドの一覧（拡張子からフロントエンドを選ぶ）
+///
+/// 既定ではJava・Kotlinのフロントエンドを登録している。
+/// 言語を追加する場合は`FrontendRegistry::default().register(...)`を`ScanOptions::frontends`に指定する。
+#[derive(Clone)]
+pub struct FrontendRegistry {
+    frontends: Vec<Arc<dyn LanguageFrontend>>,
+}
+
+impl FrontendRegistry {
+    /// フロントエンドを1つも登録していない一覧
+    pub fn empty() -> Self {
+        FrontendRegistry {
+            frontends: Vec::new(),
+        }
+    }
+
+    /// フロントエンドを登録する
+    ///
+    /// 同じ拡張子を対象とするフロントエンドがすでにある場合は、後から登録したものを使う。
+    pub fn register(mut self, frontend: impl LanguageFrontend + 'static) -> Self {
+        self.frontends.push(Arc::new(frontend));
+        self
+    }
+
+    /// ファイルの拡張子に対応するフロントエンドを返す
+    pub fn for_path(&self, path: &Path) -> Option<&dyn LanguageFrontend> {
+        let extension = path.extension()?.to_str()?;
+        self.frontends
+            .iter()
+            .rev()
+            .find(|frontend| frontend.extensions().contains(&extension))
+            .map(|frontend| frontend.as_ref())
+    }
+
+    /// 登録されているフロントエンド（登録順）
+    pub fn iter(&self) -> impl Iterator<Item = &dyn LanguageFrontend> {
+        self.frontends.iter().map(|frontend| frontend.as_ref())
+    }
+
+    // 対応するフロントエンドがないファイルはエラーにする
+    pub(crate) fn for_file(&self, file_path: &str) -> Result<&dyn LanguageFrontend> {
+        self.for_path(Path::new(file_path))
+            .with_context(|| format!("対応していない種類のファイルです: {}", file_path))
+    }
+}
+
+impl Default for FrontendRegistry {
+    fn default() -> Self {
+        FrontendRegistry::empty()
+            .register(JavaFrontend)
+            .register(KotlinFrontend)
+    }
+}
+
+impl fmt::Debug for FrontendRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|frontend| frontend.name()))
+            .finish()
+    }
+}
@@ -0,0 +1,372 @@
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::class_index::{ClassEntry, DeclaringClass, Resolution, ResolutionRecord, TypeScope};
+use crate::diagnostics::{Diagnostic, DiagnosticKind};
+use crate::frontend::{MethodDeclaration, TypeDeclaration};
+use crate::spring;
+use crate::{ClassMapping, Endpoint, FileScan, ScanContext, Supertype};
+
+// 親クラス・インターフェースを辿ってエンドポイントを継承するためのタスク
+#[derive(Debug)]
+struct InheritanceTask {
+    child_class: DeclaringClass,
+    child_mapping: ClassMapping,
+    // 親クラス名（宣言どおりの名前。パッケージ修飾されている場合もある）
+    parent_class_name: String,
+    // 親クラス名を解決するスコープ（親クラス名が書かれているファイルのpackage・import宣言）
+    scope: TypeScope,
+}
+
+impl InheritanceTask {
+    // コントローラーの親クラスと実装しているインターフェースごとにタスクを作成する
+    fn for_supertypes(
+        supertypes: Vec<Supertype>,
+        class_name: &str,
+        class_mapping: ClassMapping,
+        scope: &TypeScope,
+    ) -> Vec<Self> {
+        supertypes
+            .into_iter()
+            .map(|supertype| InheritanceTask {
+                child_class: DeclaringClass::new(scope, class_name),
+                child_mapping: class_mapping.clone(),
+                parent_class_name: supertype.name,
+                scope: scope.clone(),
+            })
+            .collect()
+    }
+
+    // 親クラスからさらなる継承タスクを作成する（インデックスに記録された親クラスのスーパータイプを使用）
+    fn for_ancestors(&self, parent: &ClassEntry) -> Vec<Self> {
+        parent
+            .supertypes
+            .iter()
+            .map(|grandparent| InheritanceTask {
+                child_class: self.child_class.clone(),
+                child_mapping: self.child_mapping.clone(),
+                parent_class_name: grandparent.name.clone(),
+                // 祖先クラス名は親クラスのファイルのimport宣言に従って解決する
+                scope: parent.scope(),
+            })
+            .collect()
+    }
+}
+
+// ディレクトリスキャン用のエンドポイント抽出関数
+// ファイルの拡張子に対応するフロントエンドで読み取った宣言からコントローラーを抽出し、親クラス・インターフェースのエンドポイントを加える
+pub(crate) fn extract_request_mapping_for_scan(
+    file_path: &str,
+    context: &ScanContext,
+) -> Result<FileScan> {
+    let frontend = context.frontends.for_file(file_path)?;
+    let source_code = fs::read_to_string(file_path)
+        .with_context(|| format!("ファイルの読み込みに失敗しました: {}", file_path))?;
+
+    let mut file_scan = FileScan::default();
+    let mut inheritance_tasks = Vec::new();
+    let mut mapped_methods = HashMap::new();
+    for declaration in frontend.extract_types(file_path, &source_code)? {
+        if !spring::is_controller(&declaration, context) {
+            continue;
+        }
+
+        // クラスレベルの@RequestMappingがない場合は、Springと同様に最も近い親クラス・インターフェースのものを使う
+        let mapping = match spring::class_mapping(&declaration, context, &mut file_scan.diagnostics)
+        {
+            Some(mapping) => mapping,
+            None => inherited_class_mapping(&declaration, context, &mut file_scan.diagnostics)
+                .unwrap_or_default(),
+        };
+        let mut endpoints =
+            spring::endpoints(&declaration, &mapping, context, &mut file_scan.diagnostics);
+        let declaring_class = DeclaringClass::new(&declaration.scope, &declaration.name);
+        declaring_class.assign_to(&mut endpoints);
+        file_scan.endpoints.extend(endpoints);
+        mapped_methods.insert(
+            declaring_class.qualified_name,
+            mapped_method_keys(&declaration.methods, context),
+        );
+        inheritance_tasks.extend(InheritanceTask::for_supertypes(
+            declaration.supertypes,
+            &declaration.name,
+            mapping,
+            &declaration.scope,
+        ));
+    }
+
+    // 継承処理（親クラスはクラスインデックスから探す）
+    process_inheritance_queue(inheritance_tasks, mapped_methods, context, &mut file_scan);
+
+    Ok(file_scan)
+}
+
+// メソッドを比較するためのキー（メソッド名と引数の数）
+type MethodKey = (String, usize);
+
+// マッピングアノテーションが付与されたメソッドのキーを集める関数
+fn mapped_method_keys(methods: &[MethodDeclaration], context: &ScanContext) -> HashSet<MethodKey> {
+    methods
+        .iter()
+        .filter(|method| spring::has_mapping(method, context.annotations()))
+        .map(method_key)
+        .collect()
+}
+
+fn method_key(method: &MethodDeclaration) -> MethodKey {
+    (method.name.clone(), method.parameters.len())
+}
+
+// 親クラス・インターフェースを近い順に辿り、最初に見つかったクラスレベルの@RequestMappingを返す関数
+// 見つからない・曖昧な親クラスはここでは報告しない（継承キューの処理で報告する）
+fn inherited_class_mapping(
+    declaration: &TypeDeclaration,
+    context: &ScanContext,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<ClassMapping> {
+    let mut visited = HashSet::new();
+    let mut queue: VecDeque<(String, TypeScope)> = declaration
+        .supertypes
+        .iter()
+        .map(|supertype| (supertype.name.clone(), declaration.scope.clone()))
+        .collect();
+
+    while let Some((type_name, scope)) = queue.pop_front() {
+        let Resolution::Found(parent) = context.class_index.resolve(&type_name, &scope) else {
+            continue;
+        };
+        if !visited.insert((parent.file_path.clone(), parent.byte_range)) {
+            continue;
+        }
+
+        // 読み込めない親クラスは継承キューの処理で報告する
+        if let Ok(Some(parent_declaration)) = load_declaration(parent, context) {
+            let mut parent_diagnostics = Vec::new();
+            let mapping =
+                spring::class_mapping(&parent_declaration, context, &mut parent_diagnostics);
+            if mapping.is_some() {
+                diagnostics.extend(
+                    parent_diagnostics
+                        .into_iter()
+                        .map(|diagnostic| diagnostic.for_class(&declaration.name)),
+                );
+                return mapping;
+            }
+        }
+
+        queue.extend(
+            parent
+                .supertypes
+                .iter()
+                .map(|grandparent| (grandparent.name.clone(), parent.scope())),
+        );
+    }
+
+    None
+}
+
+// 親クラスのファイルから読み取った宣言のキャッシュ（スキャンの間、ファイルごとに一度だけ読み込んで解析する）
+// 複数のスレッドから同時に使うため、解析はロックの外で行う
+#[derive(Default)]
+pub(crate) struct DeclarationCache {
+    declarations: Mutex<HashMap<String, Arc<Vec<TypeDeclaration>>>>,
+}
+
+impl DeclarationCache {
+    fn get_or_load(
+        &self,
+        file_path: &str,
+        context: &ScanContext,
+    ) -> Result<Arc<Vec<TypeDeclaration>>> {
+        if let Some(declarations) = self.lock().get(file_path) {
+            return Ok(Arc::clone(declarations));
+        }
+
+        let frontend = context.frontends.for_file(file_path)?;
+        let source_code = fs::read_to_string(file_path)
+            .with_context(|| format!("親クラスファイルの読み込みに失敗しました: {}", file_path))?;
+        let declarations = Arc::new(frontend.extract_types(file_path, &source_code)?);
+        self.lock()
+            .insert(file_path.to_string(), Arc::clone(&declarations));
+        Ok(declarations)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Arc<Vec<TypeDeclaration>>>> {
+        self.declarations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// クラスインデックスに記録されたクラスの宣言を、親クラスのファイルの宣言から取得する関数
+fn load_declaration(entry: &ClassEntry, context: &ScanContext) -> Result<Option<TypeDeclaration>> {
+    let declarations = context
+        .declarations
+        .get_or_load(&entry.file_path, context)?;
+
+    // ファイル内の宣言のうち、インデックスに記録されたバイト範囲のものが対象のクラス
+    Ok(declarations
+        .iter()
+        .find(|declaration| declaration.byte_range == entry.byte_range)
+        .cloned())
+}
+
+// 継承キューを処理する関数（多重継承対応）
+// 親クラスは、親クラスのファイルの拡張子に対応するフロントエンドで解析する
+// mapped_methodsは子クラスごとの、マッピング済みのメソッド（子クラスや、より近い親クラスで宣言されたもの）
+fn process_inheritance_queue(
+    queue: Vec<InheritanceTask>,
+    mut mapped_methods: HashMap<String, HashSet<MethodKey>>,
+    context: &ScanContext,
+    file_scan: &mut FileScan,
+) {
+    let mut processed_classes = HashSet::new();
+    let mut task_queue = VecDeque::from(queue);
+
+    while let Some(task) = task_queue.pop_front() {
+        // 子クラス（または祖先クラス）のpackage・import宣言に従って親クラスを解決する
+        let resolution = context
+            .class_index
+            .resolve(&task.parent_class_name, &task.scope);
+        file_scan.resolutions.push(ResolutionRecord {
+            type_name: task.parent_class_name.clone(),
+            scope: task.scope.clone(),
+            candidates: resolution.candidates(),
+        });
+        let parent = match resolution {
+            Resolution::Found(parent) => parent,
+            Resolution::Ambiguous(candidates) => {
+                // 候補が複数ある場合は推測せずに報告する
+                file_scan.diagnostics.push(
+                    Diagnostic::warning(
+                        DiagnosticKind::AmbiguousParent,
+                        &task.scope.file_path,
+                        format!(
+                            "Parent class {} is ambiguous for {}: {}",
+                            task.parent_class_name,
+                            task.child_class.name,
+                            candidates
+                                .iter()
+                                .map(|candidate| {
+                                    format!(
+                                        "{} ({})",
+                                        candidate.qualified_name(),
+                                        candidate.file_path
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    )
+                    .for_class(&task.child_class.name),
+                );
+                continue;
+            }
+            Resolution::NotFound => {
+                // Spring標準クラスや一般的なJavaクラス、設定で除外したクラスの場合は警告を出さない
+                if context
+                    .missing_parents
+                    .should_warn(&task.parent_class_name, &task.scope)
+                {
+                    file_scan.diagnostics.push(
+                        Diagnostic::warning(
+                            DiagnosticKind::UnresolvedParent,
+                            &task.scope.file_path,
+                            format!(
+                                "Parent class {} not found for {}",
+                                task.parent_class_name, task.child_class.name
+                            ),
+                        )
+                        .for_class(&task.child_class.name),
+                    );
+                }
+                continue;
+            }
+        };
+
+        // 無限ループ防止：既に処理済みのクラスはスキップ
+        let class_key = format!(
+            "{}:{}:{}",
+            parent.file_path,
+            parent.qualified_name(),
+            task.child_class.name
+        );
+        if !processed_classes.insert(class_key) {
+            continue;
+        }
+
+        let mapped_methods = mapped_methods
+            .entry(task.child_class.qualified_name.clone())
+            .or_default();
+        match extract_inherited_endpoints(
+            parent,
+            &task,
+            mapped_methods,
+            context,
+            &mut file_scan.diagnostics,
+        ) {
+            Ok(endpoints) => {
+                file_scan.endpoints.extend(endpoints);
+            }
+            Err(e) => {
+                file_scan.diagnostics.push(
+                    Diagnostic::warning(
+                        DiagnosticKind::InheritanceError,
+                        &task.scope.file_path,
+                        format!(
+                            "Failed to extract methods from parent class {}: {:#}",
+                            task.parent_class_name, e
+                        ),
+                    )
+                    .for_class(&task.child_class.name),
+                );
+                continue;
+            }
+        }
+
+        // 親クラスがさらに継承している場合、新しいタスクをキューに追加
+        task_queue.extend(task.for_ancestors(parent));
+    }
+}
+
+// 親クラス（またはインターフェース）のエンドポイントを、子クラスのマッピングを使って抽出する関数
+// 子クラスやより近い親クラスがマッピングし直しているメソッド（mapped_methods）は、重複しないよう除く
+// 親クラスで解決できなかった定数参照は、継承している子クラスの問題としてdiagnosticsに追加する
+fn extract_inherited_endpoints(
+    parent: &ClassEntry,
+    task: &InheritanceTask,
+    mapped_methods: &mut HashSet<MethodKey>,
+    context: &ScanContext,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<Endpoint>> {
+    let Some(mut declaration) = load_declaration(parent, context)? else {
+        return Ok(Vec::new());
+    };
+    declaration
+        .methods
+        .retain(|method| !mapped_methods.contains(&method_key(method)));
+    mapped_methods.extend(mapped_method_keys(&declaration.methods, context));
+
+    // 親クラスのメソッドを抽出（子クラスの@RequestMappingと、親クラス名・親ファイルパスを使用）
+    let mut parent_diagnostics = Vec::new();
+    let mut endpoints = spring::endpoints(
+        &declaration,
+        &task.child_mapping,
+        context,
+        &mut parent_diagnostics,
+    );
+    diagnostics.extend(
+        parent_diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.for_class(&task.child_class.name)),
+    );
+    DeclaringClass::from_entry(parent).assign_to(&mut endpoints);
+    // インターフェースで宣言されたエンドポイントは実装クラスのものとして扱う
+    if parent.is_interface {
+        task.child_class.assign_to(&mut endpoints);
+    }
+
+    Ok(endpoints)
+}
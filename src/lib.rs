@@ -1,40 +1,301 @@
-use anyhow::Result;
-use serde::Serialize;
-use walkdir::WalkDir;
+use anyhow::{Context, Result};
+use cache::{CacheEntry, CachedEndpoints, FileFacts, ScanCache};
+use class_index::{ClassEntry, ClassIndex, ResolutionRecord};
+use constants::ConstantTable;
+use diagnostics::{Diagnostic, DiagnosticKind, Severity};
+use frontend::FrontendRegistry;
+use inheritance::DeclarationCache;
+use mapping_annotations::MappingAnnotations;
+use missing_parent::{MissingParentFilter, MissingParentPolicy};
+use project::SourceRoot;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use source_files::{collect_source_files, SourceFiles, SourceFilter};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
 
-pub mod java;
-pub mod kotlin;
-
-// 新しいモジュール構造（将来使用予定）
-pub mod common;
+pub mod annotation;
+pub mod cache;
+pub mod class_index;
+pub mod config;
+pub mod constants;
+pub mod diagnostics;
+pub mod frontend;
+mod inheritance;
+pub mod mapping_annotations;
+pub mod missing_parent;
 pub mod parsers;
-pub mod scanner;
+pub mod project;
+pub mod source_files;
+mod spring;
+pub mod url_path;
 
 // エンドポイント情報を格納する構造体
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Endpoint {
     pub class_name: String,
+    // クラスのパッケージ（package宣言がない場合はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    // クラスの完全修飾名（ネストしたクラスは`com.example.Outer.Inner`）
+    pub qualified_class_name: String,
     pub method_name: String,
     pub http_method: String,
     pub path: String,
+    // 重複排除や比較用に正規化したパス（スラッシュの重複・末尾のスラッシュを除去）
+    pub canonical_path: String,
     pub parameters: Vec<Parameter>,
     pub line_range: (usize, usize),
     pub file_path: String,
-    pub headers: String,
-    pub params: String,
+    pub headers: Vec<Condition>,
+    pub params: Vec<Condition>,
+    pub consumes: Vec<String>,
+    pub produces: Vec<String>,
+    // エンドポイントを提供するコントローラーのGradle・Mavenのモジュール（分からない場合はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: String,
     pub param_type: String,
     pub annotation: String,
 }
 
+// headers/params属性の条件（例: "X-Version=2"、"!debug"）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    pub name: String,
+    pub operator: ConditionOperator,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionOperator {
+    // name=value
+    Equals,
+    // name!=value
+    NotEquals,
+    // name（存在すること）
+    Present,
+    // !name（存在しないこと）
+    Absent,
+}
+
+impl Condition {
+    // Springのheaders/params式を条件に変換する
+    pub fn parse(expression: &str) -> Self {
+        let expression = expression.trim();
+
+        let (name, operator, value) = if let Some((name, value)) = expression.split_once("!=") {
+            (name, ConditionOperator::NotEquals, Some(value))
+        } else if let Some((name, value)) = expression.split_once('=') {
+            (name, ConditionOperator::Equals, Some(value))
+        } else if let Some(name) = expression.strip_prefix('!') {
+            (name, ConditionOperator::Absent, None)
+        } else {
+            (expression, ConditionOperator::Present, None)
+        };
+
+        Condition {
+            name: name.trim().to_string(),
+            operator,
+            value: value.map(|value| value.trim().to_string()),
+        }
+    }
+}
+
+// クラスが継承・実装している型（型引数は除く）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Supertype {
+    // 宣言どおりの型名（パッケージ修飾を含む。例: com.example.Base）
+    pub name: String,
+    pub kind: SupertypeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SupertypeKind {
+    // 親クラス
+    Class,
+    // インターフェース
+    Interface,
+}
+
+impl Supertype {
+    // パッケージ修飾を除いた型名
+    pub fn simple_name(&self) -> &str {
+        self.name.rsplit('.').next().unwrap_or(&self.name)
+    }
+}
+
+// スキャン結果（JSON出力では診断結果を別のセクションとして出力する）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub endpoints: Vec<Endpoint>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ScanReport {
+    pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Warning)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+}
+
+// スキャンの設定
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    // 並列に処理するスレッド数（Noneの場合はCPUのコア数）
+    pub jobs: Option<usize>,
+    // 抽出結果をキャッシュするディレクトリ（Noneの場合はキャッシュを使わない）
+    pub cache_dir: Option<PathBuf>,
+    // 見つからない親クラスを警告するかどうかの設定
+    pub missing_parents: MissingParentPolicy,
+    // スキャンするファイル・除外するファイル（ルートからの相対パスのglob）
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    // ビルドの出力先（build・out・target）やツールのディレクトリ（.git・node_modulesなど）を除外するか
+    pub default_excludes: bool,
+    // .gitignoreで無視されているファイルを除外するか
+    pub gitignore: bool,
+    // マッピングアノテーションの別名（別名 → Springのアノテーション名）
+    pub mapping_annotations: BTreeMap<String, String>,
+    // すべてのエンドポイントのパスの前に付けるパス（server.servlet.context-path）
+    pub context_path: Option<String>,
+    // ファイルの拡張子ごとに使う言語フロントエンド（既定ではJava・Kotlin）
+    pub frontends: FrontendRegistry,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        ScanOptions {
+            jobs: None,
+            cache_dir: None,
+            missing_parents: MissingParentPolicy::default(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            default_excludes: true,
+            gitignore: false,
+            mapping_annotations: BTreeMap::new(),
+            context_path: None,
+            frontends: FrontendRegistry::default(),
+        }
+    }
+}
+
+// ScanOptionsを検証・コンパイルしたもの
+struct ScanSettings {
+    source_filter: SourceFilter,
+    missing_parents: MissingParentFilter,
+    annotations: MappingAnnotations,
+    frontends: FrontendRegistry,
+    // 抽出結果に影響する設定のハッシュ（設定が変わったらキャッシュを使わない）
+    hash: String,
+}
+
+impl ScanSettings {
+    fn new(options: &ScanOptions) -> Result<Self> {
+        Ok(ScanSettings {
+            source_filter: SourceFilter::new(&options.include, &options.exclude)?
+                .default_excludes(options.default_excludes)
+                .gitignore(options.gitignore),
+            missing_parents: MissingParentFilter::new(&options.missing_parents)?,
+            annotations: MappingAnnotations::new(&options.mapping_annotations)?,
+            frontends: options.frontends.clone(),
+            hash: cache::content_hash(&serde_json::to_vec(&(
+                &options.missing_parents,
+                &options.mapping_annotations,
+                options
+                    .frontends
+                    .iter()
+                    .map(|frontend| (frontend.name(), frontend.extensions()))
+                    .collect::<Vec<_>>(),
+            ))?),
+        })
+    }
+}
+
 pub fn scan_directory(dir_path: &str) -> Result<Vec<Endpoint>> {
-    scan_directory_internal(dir_path, false).map(|result| match result {
-        ScanResult::Endpoints(endpoints) => endpoints,
-        _ => unreachable!(),
+    scan_directory_with_options(dir_path, &ScanOptions::default())
+}
+
+pub fn scan_directory_with_options(dir_path: &str, options: &ScanOptions) -> Result<Vec<Endpoint>> {
+    scan_directory_report_with_options(dir_path, options).map(|report| report.endpoints)
+}
+
+/// ディレクトリをスキャンし、エンドポイントとスキャン中に見つかった問題（診断結果）を返す
+///
+/// 診断結果は標準エラー出力には出さないため、呼び出し側で表示・絞り込みを行う。
+pub fn scan_directory_report(dir_path: &str) -> Result<ScanReport> {
+    scan_directory_report_with_options(dir_path, &ScanOptions::default())
+}
+
+pub fn scan_directory_report_with_options(
+    dir_path: &str,
+    options: &ScanOptions,
+) -> Result<ScanReport> {
+    scan_directories_report_with_options(&[dir_path.to_string()], options)
+}
+
+/// 複数のディレクトリ（ソースルート）をまとめてスキャンする
+///
+/// 親クラス・定数はすべてのディレクトリから探す。出力はディレクトリの指定順、ファイルパス順に並ぶ。
+pub fn scan_directories_report_with_options(
+    dir_paths: &[String],
+    options: &ScanOptions,
+) -> Result<ScanReport> {
+    let roots: Vec<SourceRoot> = dir_paths.iter().map(SourceRoot::new).collect();
+    scan_source_roots_report(&roots, options)
+}
+
+/// モジュールの分かっているソースルートをまとめてスキャンする
+///
+/// エンドポイントには、コントローラーのファイルを含むソースルートのモジュールを設定する
+/// （親クラスから継承したエンドポイントも、子クラスのモジュールになる）。
+pub fn scan_source_roots_report(roots: &[SourceRoot], options: &ScanOptions) -> Result<ScanReport> {
+    // jobsが指定されていない場合（0を含む）はCPUのコア数だけスレッドを使う
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs.unwrap_or(0))
+        .build()
+        .context("スレッドプールの作成に失敗しました")?;
+
+    let settings = ScanSettings::new(options)?;
+    let mut report = pool.install(|| scan_files(roots, options.cache_dir.as_deref(), settings));
+
+    if let Some(context_path) = &options.context_path {
+        for endpoint in &mut report.endpoints {
+            endpoint.path = url_path::join(context_path, &endpoint.path);
+            endpoint.canonical_path = url_path::canonicalize(&endpoint.path);
+        }
+    }
+
+    Ok(report)
+}
+
+/// 1つのファイルからエンドポイントを抽出する（親クラスは`scan_root_dir`から探す）
+pub fn extract_file_report(file_path: &str, scan_root_dir: &str) -> Result<ScanReport> {
+    let mut diagnostics = Vec::new();
+    let context = ScanContext::for_single_file(scan_root_dir, &mut diagnostics)?;
+    let file_scan = inheritance::extract_request_mapping_for_scan(file_path, &context)?;
+    diagnostics.extend(file_scan.diagnostics);
+
+    Ok(ScanReport {
+        endpoints: file_scan.endpoints,
+        diagnostics,
     })
 }
 
@@ -49,50 +310,346 @@ pub fn scan_directory(dir_path: &str) -> Result<Vec<Endpoint>> {
 ///
 /// # Returns
 ///
-/// A JSON string containing an array of endpoint objects
+/// A JSON object with an `endpoints` array and a `diagnostics` array
 pub fn scan_directory_json(dir_path: &str) -> Result<String> {
-    scan_directory_internal(dir_path, true).map(|result| match result {
-        ScanResult::Json(json) => json,
-        _ => unreachable!(),
-    })
+    scan_directory_json_with_options(dir_path, &ScanOptions::default())
+}
+
+pub fn scan_directory_json_with_options(dir_path: &str, options: &ScanOptions) -> Result<String> {
+    let report = scan_directory_report_with_options(dir_path, options)?;
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+/// クラスレベルの@RequestMappingの情報（メソッドレベルの指定と組み合わせて使う）
+#[derive(Debug, Clone, Default)]
+pub struct ClassMapping {
+    pub paths: Vec<String>,
+    pub consumes: Vec<String>,
+    pub produces: Vec<String>,
+    pub headers: Vec<Condition>,
+    pub params: Vec<Condition>,
+}
+
+/// スキャン全体で共有する情報（アノテーションをSpringのマッピングとして解釈するときに使う）
+pub struct ScanContext {
+    // 他のクラスに継承されているクラスの完全修飾名
+    pub(crate) extended_classes: HashSet<String>,
+    // アノテーション引数の定数参照を解決するための定数表
+    pub(crate) constants: ConstantTable,
+    // 親クラスの探索に使うクラスインデックス
+    pub(crate) class_index: ClassIndex,
+    // 見つからない親クラスを警告するかどうか
+    pub(crate) missing_parents: MissingParentFilter,
+    // マッピングアノテーションの名前（設定で追加した別名を含む）
+    pub(crate) annotations: MappingAnnotations,
+    // ファイルの拡張子ごとに使う言語フロントエンド
+    pub(crate) frontends: FrontendRegistry,
+    // 親クラスのファイルから読み取った宣言（スキャンの間だけ保持する）
+    pub(crate) declarations: DeclarationCache,
+}
+
+impl ScanContext {
+    /// アノテーション引数の定数参照を解決するための定数表
+    pub fn constants(&self) -> &ConstantTable {
+        &self.constants
+    }
+
+    /// マッピングアノテーションの名前（設定で追加した別名を含む）
+    pub fn annotations(&self) -> &MappingAnnotations {
+        &self.annotations
+    }
+
+    /// 他のクラスに継承されているクラスか（クラスレベルのアノテーションがなければ親クラスとしてのみ扱う）
+    pub fn is_extended_class(&self, qualified_name: &str) -> bool {
+        self.extended_classes.contains(qualified_name)
+    }
+
+    // ファイルごとに抽出した定数定義・クラス宣言から構築する
+    fn from_facts<'a>(
+        facts: impl Iterator<Item = &'a FileFacts>,
+        missing_parents: MissingParentFilter,
+        annotations: MappingAnnotations,
+        frontends: FrontendRegistry,
+    ) -> Self {
+        let mut constants = ConstantTable::with_builtins();
+        let mut class_index = ClassIndex::default();
+        for file_facts in facts {
+            constants.extend(file_facts.constants.clone());
+            class_index.extend(file_facts.classes.clone());
+        }
+        ScanContext {
+            extended_classes: class_index.extended_class_names(),
+            constants,
+            class_index,
+            missing_parents,
+            annotations,
+            frontends,
+            declarations: DeclarationCache::default(),
+        }
+    }
+
+    // ファイル単位の抽出用（継承されているクラスの判定は行わない）
+    // 読み込みや解析に失敗したファイルはdiagnosticsに追加する
+    pub(crate) fn for_single_file(
+        scan_root_dir: &str,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<Self> {
+        // 同じファイルの問題を2回報告しないよう、クラス宣言の収集で見つかった問題だけを使う
+        let constants = ConstantTable::from_directory(scan_root_dir, &mut Vec::new());
+        Ok(ScanContext {
+            extended_classes: HashSet::new(),
+            constants,
+            class_index: ClassIndex::from_directory(scan_root_dir, diagnostics),
+            missing_parents: MissingParentFilter::new(&MissingParentPolicy::default())?,
+            annotations: MappingAnnotations::default(),
+            frontends: FrontendRegistry::default(),
+            declarations: DeclarationCache::default(),
+        })
+    }
 }
 
-enum ScanResult {
-    Endpoints(Vec<Endpoint>),
-    Json(String),
+// 1ファイル分のエンドポイント抽出結果
+#[derive(Debug, Default)]
+pub(crate) struct FileScan {
+    pub endpoints: Vec<Endpoint>,
+    // 親クラス・祖先クラスの解決結果（キャッシュの検証に使う）
+    pub resolutions: Vec<ResolutionRecord>,
+    // 親クラス・定数参照の解決で見つかった問題
+    pub diagnostics: Vec<Diagnostic>,
 }
 
-fn scan_directory_internal(dir_path: &str, json_output: bool) -> Result<ScanResult> {
-    let mut all_endpoints = Vec::new();
+// ディレクトリ内のファイルを並列に処理する（結果はファイルパス順に並べる）
+// cache_dirが指定されている場合は、内容が変わっていないファイルの抽出結果を再利用する
+// 読み込みや解析に失敗したファイルは診断結果として報告し、残りのファイルのスキャンを続ける
+fn scan_files(
+    roots: &[SourceRoot],
+    cache_dir: Option<&std::path::Path>,
+    settings: ScanSettings,
+) -> ScanReport {
+    // 同じファイルを複数のディレクトリ・シンボリックリンクから辿れる場合は最初の1つだけを使う
+    let SourceFiles {
+        files: source_files,
+        diagnostics: walk_diagnostics,
+    } = collect_source_files(
+        &roots
+            .iter()
+            .map(|root| root.path.clone())
+            .collect::<Vec<_>>(),
+        &settings.source_filter,
+        &settings.frontends,
+    );
+    let previous_cache = cache_dir
+        .map(|cache_dir| ScanCache::load(cache_dir, &settings.hash))
+        .unwrap_or_else(|| ScanCache::new(&settings.hash));
+
+    // ファイル単独で決まる情報（定数定義・クラス宣言）は、内容のハッシュが同じならキャッシュを使う
+    let scanned_files: Vec<Result<CacheEntry, Diagnostic>> = source_files
+        .par_iter()
+        .map(|file_path| {
+            scan_file_facts(
+                file_path,
+                &previous_cache,
+                &settings.annotations,
+                &settings.frontends,
+            )
+        })
+        .collect();
+
+    // クラスレベルのアノテーションを持たない親クラスを単独のコントローラーとして扱わないよう、
+    // 先に継承されているクラス名と定数定義を集めておく
+    let context = ScanContext::from_facts(
+        scanned_files.iter().flatten().map(|entry| &entry.facts),
+        settings.missing_parents,
+        settings.annotations,
+        settings.frontends,
+    );
+    let content_hashes: HashMap<&str, &str> = source_files
+        .iter()
+        .zip(&scanned_files)
+        .filter_map(|(file_path, scanned)| {
+            let entry = scanned.as_ref().ok()?;
+            Some((file_path.as_str(), entry.content_hash.as_str()))
+        })
+        .collect();
+    let constants_hash = cache::content_hash(
+        &serde_json::to_vec(
+            &scanned_files
+                .iter()
+                .flatten()
+                .flat_map(|entry| &entry.facts.constants)
+                .collect::<Vec<_>>(),
+        )
+        .unwrap_or_default(),
+    );
+
+    // エンドポイントは、ファイル自体と親クラス・祖先クラスの解決結果が変わっていなければキャッシュを使う
+    let endpoints_per_file: Vec<Result<Option<CachedEndpoints>, Diagnostic>> = source_files
+        .par_iter()
+        .zip(&scanned_files)
+        .map(|(file_path, scanned)| {
+            let Ok(entry) = scanned else {
+                return Ok(None);
+            };
+            if !entry.facts.has_request_mapping {
+                return Ok(None);
+            }
+
+            let extended_classes: Vec<String> = entry
+                .facts
+                .classes
+                .iter()
+                .map(ClassEntry::qualified_name)
+                .filter(|name| context.extended_classes.contains(name))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            if let Some(cached) = &entry.endpoints {
+                if cached.constants_hash == constants_hash
+                    && cached.extended_classes == extended_classes
+                    && cached.dependencies.iter().all(|(path, hash)| {
+                        content_hashes.get(path.as_str()) == Some(&hash.as_str())
+                    })
+                    && cached
+                        .resolutions
+                        .iter()
+                        .all(|resolution| resolution.still_holds(&context.class_index))
+                {
+                    return Ok(Some(cached.clone()));
+                }
+            }
+
+            let file_scan = inheritance::extract_request_mapping_for_scan(file_path, &context)
+                .map_err(|e| {
+                    Diagnostic::error(DiagnosticKind::ParseError, file_path, format!("{:#}", e))
+                })?;
+            let dependencies = file_scan
+                .resolutions
+                .iter()
+                .flat_map(|resolution| &resolution.candidates)
+                .map(|(path, _)| path.as_str())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .filter_map(|path| {
+                    content_hashes
+                        .get(path)
+                        .map(|hash| (path.to_string(), hash.to_string()))
+                })
+                .collect();
+
+            Ok(Some(CachedEndpoints {
+                endpoints: file_scan.endpoints,
+                diagnostics: file_scan.diagnostics,
+                constants_hash: constants_hash.clone(),
+                extended_classes,
+                resolutions: file_scan.resolutions,
+                dependencies,
+            }))
+        })
+        .collect();
 
-    for entry in WalkDir::new(dir_path)
-        .follow_links(true)
+    // 結果をファイルパス順にまとめ、今回スキャンしたファイルだけをキャッシュに保存する
+    // （削除されたファイルや解析に失敗したファイルのエントリは残さない）
+    let mut report = ScanReport {
+        diagnostics: walk_diagnostics,
+        ..ScanReport::default()
+    };
+    let mut cache = ScanCache::new(&settings.hash);
+    for ((file_path, scanned), endpoints) in source_files
         .into_iter()
-        .filter_map(|e| e.ok())
+        .zip(scanned_files)
+        .zip(endpoints_per_file)
     {
-        if entry.file_type().is_file() {
-            let file_path = entry.path().to_string_lossy().to_string();
-            if entry.path().extension().is_some_and(|ext| ext == "java") {
-                if java::has_request_mapping(&file_path)? {
-                    let endpoints =
-                        java::extract_request_mapping_with_inheritance(&file_path, dir_path)?;
-                    all_endpoints.extend(endpoints);
-                }
-            } else if entry.path().extension().is_some_and(|ext| ext == "kt")
-                && kotlin::has_request_mapping(&file_path)?
-            {
-                let endpoints =
-                    kotlin::extract_request_mapping_with_inheritance(&file_path, dir_path)?;
-                all_endpoints.extend(endpoints);
+        let mut entry = match scanned {
+            Ok(entry) => entry,
+            Err(diagnostic) => {
+                report.diagnostics.push(diagnostic);
+                continue;
+            }
+        };
+        report
+            .diagnostics
+            .extend(entry.facts.diagnostics.iter().cloned());
+
+        entry.endpoints = match endpoints {
+            Ok(endpoints) => endpoints,
+            Err(diagnostic) => {
+                report.diagnostics.push(diagnostic);
+                None
             }
+        };
+        if let Some(endpoints) = &entry.endpoints {
+            // モジュールはソースルートの指定で変わるため、キャッシュには含めずにここで設定する
+            let module = roots
+                .iter()
+                .find(|root| std::path::Path::new(&file_path).starts_with(&root.path))
+                .and_then(|root| root.module.clone());
+            report
+                .endpoints
+                .extend(endpoints.endpoints.iter().map(|endpoint| Endpoint {
+                    module: module.clone(),
+                    ..endpoint.clone()
+                }));
+            report
+                .diagnostics
+                .extend(endpoints.diagnostics.iter().cloned());
         }
+
+        cache.insert(file_path, entry);
     }
 
-    if json_output {
-        Ok(ScanResult::Json(serde_json::to_string_pretty(
-            &all_endpoints,
-        )?))
-    } else {
-        Ok(ScanResult::Endpoints(all_endpoints))
+    if let Some(cache_dir) = cache_dir {
+        if let Err(e) = cache.save(cache_dir) {
+            report.diagnostics.push(Diagnostic::warning(
+                DiagnosticKind::CacheError,
+                &cache_dir.to_string_lossy(),
+                format!("Failed to write scan cache: {:#}", e),
+            ));
+        }
     }
+
+    report
+}
+
+// ファイル単独で決まる情報を抽出する（内容のハッシュが同じ場合はキャッシュを使う）
+fn scan_file_facts(
+    file_path: &str,
+    previous_cache: &ScanCache,
+    annotations: &MappingAnnotations,
+    frontends: &FrontendRegistry,
+) -> Result<CacheEntry, Diagnostic> {
+    let content = fs::read(file_path).map_err(|e| {
+        Diagnostic::error(
+            DiagnosticKind::ReadError,
+            file_path,
+            format!("Failed to read file: {}", e),
+        )
+    })?;
+    let content_hash = cache::content_hash(&content);
+    if let Some(entry) = previous_cache
+        .get(file_path)
+        .filter(|entry| entry.content_hash == content_hash)
+    {
+        return Ok(entry.clone());
+    }
+
+    let source_code = String::from_utf8(content).map_err(|e| {
+        Diagnostic::error(
+            DiagnosticKind::ReadError,
+            file_path,
+            format!("File is not valid UTF-8: {}", e),
+        )
+    })?;
+    let facts = frontends
+        .for_file(file_path)
+        .and_then(|frontend| frontend.extract_file_facts(file_path, &source_code, annotations))
+        .map_err(|e| {
+            Diagnostic::error(DiagnosticKind::ParseError, file_path, format!("{:#}", e))
+        })?;
+
+    Ok(CacheEntry {
+        content_hash,
+        facts,
+        endpoints: None,
+    })
 }
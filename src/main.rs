@@ -1,6 +1,10 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use path_finder::config::{Config, OutputFormat};
+use path_finder::project::{self, SourceRoot};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -12,28 +16,213 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     ScanDirectory {
-        #[arg(default_value = "tests/resources")]
-        dir_path: String,
+        #[arg(
+            help = "Directories to scan (defaults to the roots in path-finder.toml, or tests/resources)"
+        )]
+        dir_paths: Vec<String>,
 
         #[arg(
             long,
+            help = "Scan a Gradle/Maven project directory as is instead of its modules' src/main/{java,kotlin}"
+        )]
+        no_modules: bool,
+
+        #[arg(
+            long,
+            conflicts_with = "format",
             help = "Output results in JSON format for easier parsing and integration with other tools"
         )]
         json: bool,
+
+        #[arg(long, value_name = "FORMAT", help = "Output format: text or json")]
+        format: Option<OutputFormat>,
+
+        #[arg(
+            short,
+            long,
+            help = "Number of files to process in parallel (defaults to the number of CPU cores)"
+        )]
+        jobs: Option<usize>,
+
+        #[arg(
+            long,
+            help = "Directory for the incremental scan cache; unchanged files are not parsed again (defaults to .path-finder/cache in the path-finder.toml directory, or in the first scanned directory)"
+        )]
+        cache_dir: Option<PathBuf>,
+
+        #[arg(
+            long,
+            help = "Scan every file from scratch without reading or writing the cache"
+        )]
+        no_cache: bool,
+
+        #[arg(short, long, help = "Do not print warnings; errors are still printed")]
+        quiet: bool,
+
+        #[arg(
+            long,
+            help = "Exit with a non-zero status if any warnings or errors are reported"
+        )]
+        warnings_as_errors: bool,
+
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Read settings from this TOML file instead of the discovered path-finder.toml"
+        )]
+        config: Option<PathBuf>,
+
+        #[arg(
+            long,
+            conflicts_with = "config",
+            help = "Do not look for a path-finder.toml"
+        )]
+        no_config: bool,
+
+        #[arg(
+            long,
+            value_name = "GLOB",
+            help = "Only scan files matching this glob, relative to the scanned directory (repeatable)"
+        )]
+        include: Vec<String>,
+
+        #[arg(
+            long,
+            value_name = "GLOB",
+            help = "Skip files and directories matching this glob, relative to the scanned directory (repeatable)"
+        )]
+        exclude: Vec<String>,
+
+        #[arg(long, help = "Skip files ignored by .gitignore")]
+        gitignore: bool,
+
+        #[arg(
+            long,
+            overrides_with = "gitignore",
+            help = "Scan files even if they are ignored by .gitignore"
+        )]
+        no_gitignore: bool,
+
+        #[arg(
+            long,
+            help = "Also scan build output (build/, out/, target/) and tool directories (.git/, node_modules/, ...)"
+        )]
+        no_default_excludes: bool,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Prefix every endpoint path with this context path (e.g. server.servlet.context-path)"
+        )]
+        context_path: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "PATTERN",
+            help = "Do not warn when a parent class matching this name or qualified-name glob is missing (repeatable)"
+        )]
+        suppress_parent: Vec<String>,
+
+        #[arg(
+            long,
+            value_name = "PATTERN",
+            help = "Always warn when a parent class matching this name or qualified-name glob is missing (repeatable)"
+        )]
+        warn_parent: Vec<String>,
+
+        #[arg(
+            long,
+            help = "Warn about missing Spring/JDK base classes that are suppressed by default"
+        )]
+        no_default_suppressions: bool,
     },
 }
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
 
     match &cli.command {
-        Some(Commands::ScanDirectory { dir_path, json }) => {
-            if *json {
-                let json_output = path_finder::scan_directory_json(dir_path)?;
-                println!("{}", json_output);
+        Some(Commands::ScanDirectory {
+            dir_paths,
+            no_modules,
+            json,
+            format,
+            jobs,
+            cache_dir,
+            no_cache,
+            quiet,
+            warnings_as_errors,
+            config,
+            no_config,
+            include,
+            exclude,
+            gitignore,
+            no_gitignore,
+            no_default_excludes,
+            context_path,
+            suppress_parent,
+            warn_parent,
+            no_default_suppressions,
+        }) => {
+            // 設定ファイルはスキャンするディレクトリ（未指定の場合はカレントディレクトリ）から探す
+            let (config, config_dir) = match config {
+                Some(path) => (Config::load(path)?, Some(parent_dir(path))),
+                None if *no_config => (Config::default(), None),
+                None => {
+                    let start_dir = Path::new(dir_paths.first().map_or(".", String::as_str));
+                    match Config::discover(start_dir)? {
+                        Some((path, config)) => {
+                            (config, Some(relative_to_current_dir(&parent_dir(&path))))
+                        }
+                        None => (Config::default(), None),
+                    }
+                }
+            };
+            let roots = scan_roots(dir_paths, &config, config_dir.as_deref(), !*no_modules)?;
+
+            // CLIの指定は設定ファイルより優先する（ファイル・親クラスのパターンは設定ファイルのパターンに追加する）
+            let mut options = config.scan_options();
+            options.jobs = *jobs;
+            options.cache_dir = (!*no_cache).then(|| {
+                cache_dir
+                    .clone()
+                    .unwrap_or_else(|| default_cache_dir(config_dir.as_deref(), dir_paths, &roots))
+            });
+            options.include.extend(include.iter().cloned());
+            options.exclude.extend(exclude.iter().cloned());
+            if *gitignore || *no_gitignore {
+                options.gitignore = *gitignore;
+            }
+            if *no_default_excludes {
+                options.default_excludes = false;
+            }
+            if context_path.is_some() {
+                options.context_path = context_path.clone();
+            }
+            options
+                .missing_parents
+                .merge(path_finder::missing_parent::MissingParentPolicy {
+                    suppress: suppress_parent.clone(),
+                    warn: warn_parent.clone(),
+                    suppress_defaults: !*no_default_suppressions,
+                });
+            let format = match (json, format) {
+                (true, _) => OutputFormat::Json,
+                (false, Some(format)) => *format,
+                (false, None) => config.format.unwrap_or_default(),
+            };
+
+            let report = path_finder::scan_source_roots_report(&roots, &options)?;
+            if format == OutputFormat::Json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
             } else {
-                let endpoints = path_finder::scan_directory(dir_path)?;
-                print_endpoints_summary(&endpoints);
+                print_endpoints_summary(&report.endpoints);
+            }
+            print_diagnostics(&report, *quiet);
+
+            // 警告をエラーとして扱う場合は、警告があれば失敗とする
+            if report.has_errors() || (*warnings_as_errors && !report.diagnostics.is_empty()) {
+                return Ok(ExitCode::FAILURE);
             }
         }
         None => {
@@ -43,7 +232,102 @@ fn main() -> Result<()> {
         }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
+}
+
+// スキャンするディレクトリを決める
+//
+// ディレクトリを指定した場合はそのディレクトリをスキャンする。ただし、設定ファイルのディレクトリだけを
+// 指定した場合は、設定ファイルのrootsをスキャンする。
+// Gradle・Mavenのプロジェクトのディレクトリは、各モジュールのソースディレクトリに展開する。
+fn scan_roots(
+    dir_paths: &[String],
+    config: &Config,
+    config_dir: Option<&Path>,
+    discover_modules: bool,
+) -> Result<Vec<SourceRoot>> {
+    let dir_paths = match (dir_paths, config_dir) {
+        ([], Some(config_dir)) => config.root_paths(config_dir),
+        ([], None) => vec!["tests/resources".to_string()],
+        ([dir_path], Some(config_dir)) if is_same_dir(Path::new(dir_path), config_dir) => {
+            config.root_paths(config_dir)
+        }
+        _ => dir_paths.to_vec(),
+    };
+
+    let mut roots = Vec::new();
+    for dir_path in dir_paths {
+        let modules = if discover_modules && project::is_project_root(Path::new(&dir_path)) {
+            project::discover_source_roots(Path::new(&dir_path))?
+        } else {
+            Vec::new()
+        };
+        // モジュールのソースディレクトリが見つからない場合はディレクトリ全体をスキャンする
+        if modules.is_empty() {
+            roots.push(SourceRoot::new(dir_path));
+        } else {
+            roots.extend(modules);
+        }
+    }
+    Ok(roots)
+}
+
+// キャッシュディレクトリの既定値
+// スキャンするディレクトリごとにキャッシュを分けるため、カレントディレクトリではなく
+// 設定ファイルのディレクトリ（設定ファイルがない場合は最初に指定したディレクトリ）に置く
+fn default_cache_dir(
+    config_dir: Option<&Path>,
+    dir_paths: &[String],
+    roots: &[SourceRoot],
+) -> PathBuf {
+    let base_dir = match (config_dir, dir_paths.first(), roots.first()) {
+        (Some(config_dir), _, _) => config_dir.to_path_buf(),
+        (None, Some(dir_path), _) => PathBuf::from(dir_path),
+        (None, None, Some(root)) => PathBuf::from(&root.path),
+        (None, None, None) => PathBuf::from("."),
+    };
+    base_dir.join(path_finder::cache::DEFAULT_CACHE_DIR)
+}
+
+fn is_same_dir(a: &Path, b: &Path) -> bool {
+    matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+// 出力するファイルパスが長くならないよう、カレントディレクトリ以下のパスは相対パスにする
+fn relative_to_current_dir(path: &Path) -> PathBuf {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|current_dir| current_dir.canonicalize().ok())
+        .and_then(|current_dir| path.strip_prefix(current_dir).ok().map(Path::to_path_buf));
+    match relative {
+        Some(relative) if relative.as_os_str().is_empty() => PathBuf::from("."),
+        Some(relative) => relative,
+        None => path.to_path_buf(),
+    }
+}
+
+// 診断結果を標準エラー出力に表示する（quietの場合はエラーのみ）
+fn print_diagnostics(report: &path_finder::ScanReport, quiet: bool) {
+    if quiet {
+        for diagnostic in report.errors() {
+            eprintln!("{}", diagnostic);
+        }
+        return;
+    }
+
+    for diagnostic in &report.diagnostics {
+        eprintln!("{}", diagnostic);
+    }
+    if let Some(summary) = path_finder::diagnostics::summarize(&report.diagnostics) {
+        eprintln!("path-finder: {}", summary);
+    }
 }
 
 fn print_endpoints_summary(endpoints: &[path_finder::Endpoint]) {
@@ -54,6 +338,9 @@ fn print_endpoints_summary(endpoints: &[path_finder::Endpoint]) {
             "PUT" => "PUT".blue(),
             "DELETE" => "DELETE".red(),
             "PATCH" => "PATCH".cyan(),
+            "HEAD" => "HEAD".bright_green(),
+            "OPTIONS" => "OPTIONS".bright_blue(),
+            "TRACE" => "TRACE".bright_black(),
             "ANY" => "ANY".magenta(),
             _ => endpoint.http_method.normal(),
         };
@@ -68,6 +355,11 @@ fn print_endpoints_summary(endpoints: &[path_finder::Endpoint]) {
             endpoint.line_range.0,
         );
 
+        // モジュールが分かれば表示
+        if let Some(module) = &endpoint.module {
+            println!("  module: {}", module);
+        }
+
         // パラメータがあれば表示
         if !endpoint.parameters.is_empty() {
             print!("  parameters: ");
@@ -79,5 +371,13 @@ fn print_endpoints_summary(endpoints: &[path_finder::Endpoint]) {
             }
             println!();
         }
+
+        // consumes/producesがあれば表示
+        if !endpoint.consumes.is_empty() {
+            println!("  consumes: {}", endpoint.consumes.join(", "));
+        }
+        if !endpoint.produces.is_empty() {
+            println!("  produces: {}", endpoint.produces.join(", "));
+        }
     }
 }
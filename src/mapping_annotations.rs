@@ -0,0 +1,97 @@
+use anyhow::{bail, Result};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::annotation::Annotation;
+
+// 別名の対象にできるSpringのアノテーション
+const CONTROLLER_ANNOTATIONS: &[&str] = &["Controller", "RestController"];
+const METHOD_MAPPING_ANNOTATIONS: &[&str] = &[
+    "RequestMapping",
+    "GetMapping",
+    "PostMapping",
+    "PutMapping",
+    "DeleteMapping",
+    "PatchMapping",
+];
+
+// Springのアノテーションのパッケージ（@Controllerだけstereotypeパッケージにある）
+const STEREOTYPE_PACKAGE: &str = "org.springframework.stereotype";
+const WEB_ANNOTATION_PACKAGE: &str = "org.springframework.web.bind.annotation";
+
+/// マッピングアノテーションの名前（Springのアノテーションと、設定で追加した別名）
+///
+/// 別名は`@ApiGet`を`@GetMapping`として扱うように、独自のアノテーション名をSpringの
+/// アノテーション名に対応付ける（属性はSpringのアノテーションと同じように読む）。
+#[derive(Debug, Clone, Default)]
+pub struct MappingAnnotations {
+    aliases: HashMap<String, String>,
+}
+
+impl MappingAnnotations {
+    pub fn new(aliases: &BTreeMap<String, String>) -> Result<Self> {
+        for (alias, target) in aliases {
+            if !CONTROLLER_ANNOTATIONS.contains(&target.as_str())
+                && !METHOD_MAPPING_ANNOTATIONS.contains(&target.as_str())
+            {
+                bail!(
+                    "アノテーションの別名{}の対象{}はSpringのマッピングアノテーションではありません",
+                    alias,
+                    target
+                );
+            }
+        }
+
+        Ok(MappingAnnotations {
+            aliases: aliases.clone().into_iter().collect(),
+        })
+    }
+
+    /// 別名を含むアノテーションをSpringのアノテーション名に変換する
+    ///
+    /// 完全修飾名が分かる場合は完全修飾名から判定する（Kotlinの`import ... as Get`による別名も元の名前になる）。
+    /// Springのパッケージのアノテーションでない場合（`@com.acme.GetMapping`など）は、Springのアノテーションとして
+    /// 扱わずNoneを返す。設定で追加した別名は完全修飾名によらず変換する。
+    pub fn canonical<'a>(&'a self, annotation: &'a Annotation) -> Option<&'a str> {
+        if let Some(target) = self.aliases.get(&annotation.name) {
+            return Some(target);
+        }
+
+        let Some(qualified_name) = &annotation.qualified_name else {
+            return Some(&annotation.name);
+        };
+        let (package, name) = qualified_name.rsplit_once('.')?;
+        let spring_package = if name == "Controller" {
+            STEREOTYPE_PACKAGE
+        } else {
+            WEB_ANNOTATION_PACKAGE
+        };
+        (package == spring_package).then_some(name)
+    }
+
+    /// クラスレベルでコントローラーを表すアノテーションか（`@RequestMapping`を含む）
+    pub fn is_controller(&self, annotation: &Annotation) -> bool {
+        self.canonical(annotation)
+            .is_some_and(|name| CONTROLLER_ANNOTATIONS.contains(&name) || name == "RequestMapping")
+    }
+
+    /// `@RequestMapping`（またはその別名）か
+    pub fn is_request_mapping(&self, annotation: &Annotation) -> bool {
+        self.canonical(annotation) == Some("RequestMapping")
+    }
+
+    /// メソッドレベルのマッピングアノテーションか
+    pub fn is_method_mapping(&self, annotation: &Annotation) -> bool {
+        self.canonical(annotation)
+            .is_some_and(|name| METHOD_MAPPING_ANNOTATIONS.contains(&name))
+    }
+
+    /// ソースにマッピングアノテーションが含まれる可能性があるか（構文解析の前の簡易判定）
+    pub(crate) fn may_appear_in(&self, source_code: &str) -> bool {
+        source_code.contains("Mapping")
+            || source_code.contains("Controller")
+            || self
+                .aliases
+                .keys()
+                .any(|alias| source_code.contains(alias.as_str()))
+    }
+}
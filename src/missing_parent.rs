@@ -0,0 +1,167 @@
+use anyhow::{Context, Result};
+use globset::{Glob, GlobMatcher};
+use serde::{Deserialize, Serialize};
+
+use crate::class_index::TypeScope;
+
+// 見つからなくても警告しない親クラス名（Spring標準クラスや一般的なJavaクラスなど）
+const DEFAULT_SUPPRESSED_PARENTS: &[&str] = &[
+    // Java標準クラス
+    "Object",
+    "Exception",
+    "RuntimeException",
+    "Throwable",
+    "Enum",
+    "Record",
+    "Number",
+    "String",
+    "Serializable",
+    "Cloneable",
+    "Comparable",
+    "AutoCloseable",
+    "Closeable",
+    // Spring Boot / Spring Framework標準クラス
+    "BaseEntity",
+    "AbstractEntity",
+    "AbstractAggregateRoot",
+    "JpaRepository",
+    "CrudRepository",
+    "Repository",
+    "PagingAndSortingRepository",
+    "Controller",
+    "RestController",
+    "Component",
+    "Service",
+    "Configuration",
+    "ConfigurationProperties",
+    // JPA / Hibernate標準クラス
+    "EntityListener",
+    "AbstractEntityListener",
+    "Auditable",
+    "Persistable",
+    "AbstractAuditable",
+    "AbstractPersistable",
+    // 一般的なライブラリクラス
+    "ResponseEntity",
+    "HttpEntity",
+    "RequestEntity",
+    "Page",
+    "Pageable",
+    "Sort",
+    "Slice",
+    // よくあるベースクラス名
+    "BaseController",
+    "AbstractController",
+    "BaseService",
+    "AbstractService",
+    "BaseRepository",
+    "AbstractRepository",
+    "BaseDomain",
+    "AbstractDomain",
+    "BaseDto",
+    "AbstractDto",
+];
+
+/// 見つからない親クラスを警告するかどうかの設定
+///
+/// パターンはクラス名（`BaseController`、`*Dto`）または完全修飾名（`org.springframework.*`）の
+/// globで指定する。`.`を含むパターンは完全修飾名と照合する。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MissingParentPolicy {
+    /// 警告しない親クラス
+    pub suppress: Vec<String>,
+    /// 必ず警告する親クラス（`suppress`や組み込みのリストより優先する）
+    pub warn: Vec<String>,
+    /// 組み込みのリスト（Spring標準クラスや一般的なJavaクラス）の警告を抑制するか
+    pub suppress_defaults: bool,
+}
+
+impl Default for MissingParentPolicy {
+    fn default() -> Self {
+        MissingParentPolicy {
+            suppress: Vec::new(),
+            warn: Vec::new(),
+            suppress_defaults: true,
+        }
+    }
+}
+
+impl MissingParentPolicy {
+    /// 別の設定のパターンを追加する（CLIの指定を設定ファイルに追加する場合など）
+    pub fn merge(&mut self, other: MissingParentPolicy) {
+        self.suppress.extend(other.suppress);
+        self.warn.extend(other.warn);
+        self.suppress_defaults &= other.suppress_defaults;
+    }
+}
+
+/// `MissingParentPolicy`のパターンをコンパイルしたもの
+#[derive(Debug, Clone)]
+pub struct MissingParentFilter {
+    suppress: Vec<ParentPattern>,
+    warn: Vec<ParentPattern>,
+}
+
+#[derive(Debug, Clone)]
+struct ParentPattern {
+    // 完全修飾名と照合するか（`.`を含むパターン）
+    qualified: bool,
+    matcher: GlobMatcher,
+}
+
+impl ParentPattern {
+    fn new(pattern: &str) -> Result<Self> {
+        let glob = Glob::new(pattern)
+            .with_context(|| format!("親クラスのパターンが不正です: {}", pattern))?;
+        Ok(ParentPattern {
+            qualified: pattern.contains('.'),
+            matcher: glob.compile_matcher(),
+        })
+    }
+
+    fn matches(&self, simple_name: &str, qualified_names: &[String]) -> bool {
+        if self.qualified {
+            qualified_names
+                .iter()
+                .any(|qualified_name| self.matcher.is_match(qualified_name))
+        } else {
+            self.matcher.is_match(simple_name)
+        }
+    }
+}
+
+impl MissingParentFilter {
+    pub fn new(policy: &MissingParentPolicy) -> Result<Self> {
+        let defaults: &[&str] = if policy.suppress_defaults {
+            DEFAULT_SUPPRESSED_PARENTS
+        } else {
+            &[]
+        };
+        let suppress = defaults
+            .iter()
+            .copied()
+            .chain(policy.suppress.iter().map(String::as_str))
+            .map(ParentPattern::new)
+            .collect::<Result<_>>()?;
+        let warn = policy
+            .warn
+            .iter()
+            .map(|pattern| ParentPattern::new(pattern))
+            .collect::<Result<_>>()?;
+        Ok(MissingParentFilter { suppress, warn })
+    }
+
+    /// 見つからない親クラスを警告するか（`type_name`はソース上の名前、`scope`は参照している位置）
+    pub fn should_warn(&self, type_name: &str, scope: &TypeScope) -> bool {
+        let simple_name = type_name.rsplit('.').next().unwrap_or(type_name);
+        let qualified_names = scope.presumed_qualified_names(type_name);
+        let matches = |patterns: &[ParentPattern]| {
+            patterns
+                .iter()
+                .any(|pattern| pattern.matches(simple_name, &qualified_names))
+        };
+
+        matches(&self.warn) || !matches(&self.suppress)
+    }
+}
@@ -0,0 +1,618 @@
+use anyhow::{Context, Result};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
+use tree_sitter::{Parser, Query, QueryCursor, StreamingIterator, Tree};
+
+use crate::annotation::{Annotation, AnnotationValue};
+use crate::cache::FileFacts;
+use crate::class_index::{Import, TypeScope};
+use crate::constants::{self, ConstantDefinition, ConstantExpr};
+use crate::diagnostics::{self, Diagnostic};
+use crate::frontend::{LanguageFrontend, MethodDeclaration, ParameterDeclaration, TypeDeclaration};
+use crate::mapping_annotations::MappingAnnotations;
+use crate::spring;
+use crate::{Supertype, SupertypeKind};
+
+fn create_parser() -> Result<Parser> {
+    let mut parser = Parser::new();
+    let language = tree_sitter_java::LANGUAGE;
+    parser
+        .set_language(&language.into())
+        .context("Javaパーサーの読み込みに失敗しました")?;
+    Ok(parser)
+}
+
+// スレッドごとにパーサーを1つだけ生成して使い回す
+thread_local! {
+    static PARSER: RefCell<Option<Parser>> = const { RefCell::new(None) };
+}
+
+fn parse(source_code: &str) -> Result<Tree> {
+    PARSER.with(|parser| {
+        let mut parser = parser.borrow_mut();
+        if parser.is_none() {
+            *parser = Some(create_parser()?);
+        }
+        parser
+            .as_mut()
+            .and_then(|parser| parser.parse(source_code, None))
+            .context("構文解析に失敗しました")
+    })
+}
+
+// クエリは初回にコンパイルし、以降はスレッド間で共有して使い回す
+fn create_query(query_source: &'static str) -> Result<&'static Query> {
+    static QUERIES: OnceLock<Mutex<HashMap<&'static str, &'static Query>>> = OnceLock::new();
+
+    let mut queries = QUERIES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(query) = queries.get(query_source) {
+        return Ok(query);
+    }
+
+    let language = tree_sitter_java::LANGUAGE;
+    let query = Query::new(&language.into(), query_source)
+        .with_context(|| format!("クエリのコンパイルに失敗しました: {}", query_source.trim()))?;
+    let query: &'static Query = Box::leak(Box::new(query));
+    queries.insert(query_source, query);
+    Ok(query)
+}
+
+// ファイルにコントローラーが含まれるか判定する関数
+fn contains_controller(
+    source_code: &str,
+    types: &[TypeDeclaration],
+    annotations: &MappingAnnotations,
+) -> bool {
+    // Simple string search for quick check before querying
+    annotations.may_appear_in(source_code) && spring::contains_controller(types, annotations)
+}
+
+// メソッドがクラスの直接のメンバーか判定する関数（ネストしたクラスのメソッドを除く）
+fn is_member_of(method_node: tree_sitter::Node, class_node: tree_sitter::Node) -> bool {
+    let mut current = method_node.parent();
+    while let Some(parent) = current {
+        if matches!(
+            parent.kind(),
+            "class_declaration"
+                | "interface_declaration"
+                | "enum_declaration"
+                | "record_declaration"
+        ) {
+            return parent.id() == class_node.id();
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+// パース済みの構文木からクラス・インターフェース宣言を、アノテーションとメソッドを含めて抽出する関数
+fn extract_types_from_tree(
+    file_path: &str,
+    source_code: &str,
+    root_node: tree_sitter::Node,
+) -> Result<Vec<TypeDeclaration>> {
+    let scope = TypeScope {
+        file_path: file_path.to_string(),
+        package: extract_package_name(source_code, root_node),
+        imports: extract_imports(source_code, root_node, false),
+        static_imports: extract_imports(source_code, root_node, true),
+        enclosing_class: None,
+    };
+
+    let query_source = r#"
+        [
+            (class_declaration name: (identifier) @class_name)
+            (interface_declaration name: (identifier) @class_name)
+        ] @class
+    "#;
+
+    let query = create_query(query_source)?;
+    let mut query_cursor = QueryCursor::new();
+    let mut matches = query_cursor.matches(query, root_node, source_code.as_bytes());
+
+    let mut types = Vec::new();
+
+    while let Some(m) = matches.next() {
+        let mut class_name = "";
+        let mut class_node = None;
+
+        for capture in m.captures {
+            let capture_name = &query.capture_names()[capture.index as usize];
+            match *capture_name {
+                "class_name" => class_name = &source_code[capture.node.byte_range()],
+                "class" => class_node = Some(capture.node),
+                _ => {}
+            }
+        }
+
+        if let Some(class_node) = class_node {
+            types.push(TypeDeclaration {
+                name: class_name.to_string(),
+                scope: TypeScope {
+                    enclosing_class: enclosing_type_name(source_code, class_node),
+                    ..scope.clone()
+                },
+                byte_range: (class_node.start_byte(), class_node.end_byte()),
+                is_interface: class_node.kind() == "interface_declaration",
+                supertypes: extract_inheritance_info(source_code, class_node),
+                annotations: extract_annotations(source_code, class_node, &scope.imports),
+                methods: extract_methods(source_code, class_node, &scope.imports)?,
+            });
+        }
+    }
+
+    Ok(types)
+}
+
+// クラスの直接のメンバーであるメソッドを抽出する関数
+fn extract_methods(
+    source_code: &str,
+    class_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Result<Vec<MethodDeclaration>> {
+    let query_source = r#"
+        (method_declaration
+            name: (identifier) @method_name) @method
+    "#;
+
+    let query = create_query(query_source)?;
+    let mut query_cursor = QueryCursor::new();
+    let mut matches = query_cursor.matches(query, class_node, source_code.as_bytes());
+
+    let mut methods = Vec::new();
+
+    while let Some(m) = matches.next() {
+        let mut method_name = "";
+        let mut method_node = None;
+
+        for capture in m.captures {
+            let capture_name = &query.capture_names()[capture.index as usize];
+            match *capture_name {
+                "method_name" => method_name = &source_code[capture.node.byte_range()],
+                "method" => method_node = Some(capture.node),
+                _ => {}
+            }
+        }
+
+        // ネストしたクラスのメソッドは、そのクラスとして別に抽出する
+        let Some(method_node) = method_node.filter(|node| is_member_of(*node, class_node)) else {
+            continue;
+        };
+
+        methods.push(MethodDeclaration {
+            name: method_name.to_string(),
+            line_range: (
+                method_node.start_position().row + 1,
+                method_node.end_position().row + 1,
+            ),
+            annotations: extract_annotations(source_code, method_node, imports),
+            parameters: extract_parameters(source_code, method_node, imports),
+        });
+    }
+
+    Ok(methods)
+}
+
+// メソッドの引数を抽出する関数
+fn extract_parameters(
+    source_code: &str,
+    method_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Vec<ParameterDeclaration> {
+    let Some(parameters) = method_node.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = parameters.walk();
+    parameters
+        .named_children(&mut cursor)
+        .filter(|parameter| parameter.kind() == "formal_parameter")
+        .filter_map(|parameter| {
+            let name_node = parameter.child_by_field_name("name")?;
+            let type_node = parameter.child_by_field_name("type")?;
+            Some(ParameterDeclaration {
+                name: source_code[name_node.byte_range()].to_string(),
+                param_type: source_code[type_node.byte_range()].to_string(),
+                annotations: extract_annotations(source_code, parameter, imports),
+            })
+        })
+        .collect()
+}
+
+// 宣言（クラス・メソッド・引数）の修飾子に含まれるアノテーションを抽出する関数
+fn extract_annotations(
+    source_code: &str,
+    declaration_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Vec<Annotation> {
+    let mut cursor = declaration_node.walk();
+    let Some(modifiers) = declaration_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "modifiers")
+    else {
+        return Vec::new();
+    };
+
+    let mut modifiers_cursor = modifiers.walk();
+    modifiers
+        .named_children(&mut modifiers_cursor)
+        .filter(|modifier| matches!(modifier.kind(), "annotation" | "marker_annotation"))
+        .filter_map(|annotation_node| to_annotation(source_code, annotation_node, imports))
+        .collect()
+}
+
+// アノテーションノードを言語によらない形に変換する関数（名前なしの引数は"value"として扱う）
+fn to_annotation(
+    source_code: &str,
+    annotation_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Option<Annotation> {
+    let name_node = annotation_node.child_by_field_name("name")?;
+    let mut annotation = Annotation::new(&source_code[name_node.byte_range()], imports);
+
+    if let Some(arguments) = annotation_node.child_by_field_name("arguments") {
+        let mut cursor = arguments.walk();
+        for argument in arguments.named_children(&mut cursor) {
+            match argument.kind() {
+                "element_value_pair" => {
+                    let (Some(key_node), Some(value_node)) = (
+                        argument.child_by_field_name("key"),
+                        argument.child_by_field_name("value"),
+                    ) else {
+                        continue;
+                    };
+                    annotation.args.insert(
+                        source_code[key_node.byte_range()].to_string(),
+                        to_annotation_value(source_code, value_node),
+                    );
+                }
+                "comment" => {}
+                _ => {
+                    annotation.args.insert(
+                        "value".to_string(),
+                        to_annotation_value(source_code, argument),
+                    );
+                }
+            }
+        }
+    }
+
+    Some(annotation)
+}
+
+// アノテーションの引数の値を変換する関数（配列は各要素を変換する）
+fn to_annotation_value(source_code: &str, value_node: tree_sitter::Node) -> AnnotationValue {
+    if value_node.kind() == "element_value_array_initializer" {
+        let mut cursor = value_node.walk();
+        return AnnotationValue::Array(
+            value_node
+                .named_children(&mut cursor)
+                .filter(|element| element.kind() != "comment")
+                .map(|element| to_annotation_value(source_code, element))
+                .collect(),
+        );
+    }
+
+    AnnotationValue::Expr {
+        text: source_code[value_node.byte_range()].to_string(),
+        expr: to_constant_expr(source_code, value_node),
+    }
+}
+
+// キャッシュの単位となる、ファイル単独で決まる情報をまとめて抽出する関数（パースは1回だけ）
+fn extract_file_facts(
+    file_path: &str,
+    source_code: &str,
+    annotations: &MappingAnnotations,
+) -> Result<FileFacts> {
+    let tree = parse(source_code)?;
+    let root_node = tree.root_node();
+    let types = extract_types_from_tree(file_path, source_code, root_node)?;
+
+    Ok(FileFacts {
+        has_request_mapping: contains_controller(source_code, &types, annotations),
+        constants: extract_constants_from_tree(source_code, root_node)?,
+        classes: types.iter().map(TypeDeclaration::class_entry).collect(),
+        diagnostics: diagnostics::syntax_errors_near_annotations(file_path, source_code, root_node),
+    })
+}
+
+// 式ノードを定数式に変換する関数（文字列リテラル・定数参照・+による結合のみ対応）
+fn to_constant_expr(source_code: &str, node: tree_sitter::Node) -> Option<ConstantExpr> {
+    match node.kind() {
+        "string_literal" => {
+            let mut value = String::new();
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                let text = &source_code[child.byte_range()];
+                match child.kind() {
+                    "string_fragment" | "multiline_string_fragment" => value.push_str(text),
+                    "escape_sequence" => value.push_str(&constants::unescape(text)),
+                    _ => return None,
+                }
+            }
+            Some(ConstantExpr::Literal(value))
+        }
+        "identifier" | "field_access" => {
+            let text = &source_code[node.byte_range()];
+            Some(ConstantExpr::Reference(
+                text.chars().filter(|c| !c.is_whitespace()).collect(),
+            ))
+        }
+        "parenthesized_expression" => to_constant_expr(source_code, node.named_child(0)?),
+        "binary_expression" => {
+            let operator = node.child_by_field_name("operator")?;
+            if &source_code[operator.byte_range()] != "+" {
+                return None;
+            }
+            let left = to_constant_expr(source_code, node.child_by_field_name("left")?)?;
+            let right = to_constant_expr(source_code, node.child_by_field_name("right")?)?;
+            Some(ConstantExpr::Concat(vec![left, right]))
+        }
+        _ => None,
+    }
+}
+
+// パース済みの構文木から定数定義を抽出する関数
+fn extract_constants_from_tree(
+    source_code: &str,
+    root_node: tree_sitter::Node,
+) -> Result<Vec<ConstantDefinition>> {
+    // Simple string search for quick check before querying
+    if !source_code.contains("final") && !source_code.contains("interface") {
+        return Ok(Vec::new());
+    }
+
+    let query_source = r#"
+        (field_declaration
+            (modifiers) @modifiers
+            type: (type_identifier) @type
+            (#eq? @type "String")
+            declarator: (variable_declarator
+                name: (identifier) @name
+                value: (_) @value))
+
+        (constant_declaration
+            type: (type_identifier) @type
+            (#eq? @type "String")
+            declarator: (variable_declarator
+                name: (identifier) @name
+                value: (_) @value))
+    "#;
+
+    let query = create_query(query_source)?;
+    let mut query_cursor = QueryCursor::new();
+    let mut matches = query_cursor.matches(query, root_node, source_code.as_bytes());
+
+    let package = extract_package_name(source_code, root_node);
+    let imports: Vec<Import> = extract_imports(source_code, root_node, false)
+        .into_iter()
+        .chain(extract_imports(source_code, root_node, true))
+        .collect();
+    let mut definitions = Vec::new();
+
+    while let Some(m) = matches.next() {
+        let mut name = "";
+        let mut value_node = None;
+        let mut is_constant = true;
+
+        for capture in m.captures {
+            let capture_name = &query.capture_names()[capture.index as usize];
+            match *capture_name {
+                "name" => name = &source_code[capture.node.byte_range()],
+                "value" => value_node = Some(capture.node),
+                "modifiers" => {
+                    // クラスのフィールドはstatic finalのものだけを定数とみなす
+                    let mut cursor = capture.node.walk();
+                    let kinds: Vec<&str> = capture
+                        .node
+                        .children(&mut cursor)
+                        .map(|child| child.kind())
+                        .collect();
+                    is_constant = kinds.contains(&"static") && kinds.contains(&"final");
+                }
+                _ => {}
+            }
+        }
+
+        if !is_constant {
+            continue;
+        }
+
+        if let Some(expr) = value_node.and_then(|node| to_constant_expr(source_code, node)) {
+            definitions.push(ConstantDefinition {
+                package: package.clone(),
+                owner: value_node.and_then(|node| enclosing_type_name(source_code, node)),
+                name: name.to_string(),
+                expr,
+                imports: imports.clone(),
+            });
+        }
+    }
+
+    Ok(definitions)
+}
+
+// package宣言からパッケージ名を取得する関数
+fn extract_package_name(source_code: &str, root_node: tree_sitter::Node) -> Option<String> {
+    let mut cursor = root_node.walk();
+    let package_node = root_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "package_declaration")?;
+    let name_node = package_node.named_child(0)?;
+
+    Some(source_code[name_node.byte_range()].to_string())
+}
+
+// import宣言を取得する関数（static importはクラスの解決に使わないため、`is_static`で分けて取得する）
+fn extract_imports(
+    source_code: &str,
+    root_node: tree_sitter::Node,
+    is_static: bool,
+) -> Vec<Import> {
+    let mut cursor = root_node.walk();
+    root_node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "import_declaration")
+        .filter_map(|import_node| {
+            let mut import_cursor = import_node.walk();
+            let children: Vec<_> = import_node.children(&mut import_cursor).collect();
+            if children.iter().any(|child| child.kind() == "static") != is_static {
+                return None;
+            }
+
+            let name_node = children
+                .iter()
+                .find(|child| matches!(child.kind(), "scoped_identifier" | "identifier"))?;
+            Some(Import {
+                path: source_code[name_node.byte_range()].to_string(),
+                wildcard: children.iter().any(|child| child.kind() == "asterisk"),
+                alias: None,
+            })
+        })
+        .collect()
+}
+
+// ノードを囲む型名を取得する関数（ネストしている場合は`Outer.Inner`）
+fn enclosing_type_name(source_code: &str, node: tree_sitter::Node) -> Option<String> {
+    let mut names = Vec::new();
+    let mut current = node.parent();
+
+    while let Some(parent) = current {
+        if matches!(
+            parent.kind(),
+            "class_declaration" | "interface_declaration" | "enum_declaration"
+        ) {
+            if let Some(name_node) = parent.child_by_field_name("name") {
+                names.push(name_node);
+            }
+        }
+        current = parent.parent();
+    }
+
+    if names.is_empty() {
+        return None;
+    }
+
+    Some(
+        names
+            .iter()
+            .rev()
+            .map(|name_node| &source_code[name_node.byte_range()])
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+// 継承情報を抽出する関数
+fn extract_inheritance_info(source_code: &str, class_node: tree_sitter::Node) -> Vec<Supertype> {
+    let mut supertypes = Vec::new();
+
+    // extends（クラスの親クラス）
+    if let Some(superclass) = class_node.child_by_field_name("superclass") {
+        let mut cursor = superclass.walk();
+        supertypes.extend(
+            superclass
+                .named_children(&mut cursor)
+                .filter_map(|type_node| type_name(source_code, type_node))
+                .map(|name| Supertype {
+                    name,
+                    kind: SupertypeKind::Class,
+                }),
+        );
+    }
+
+    // implements（クラスが実装するインターフェース）、extends（インターフェースの親インターフェース）
+    let mut cursor = class_node.walk();
+    for child in class_node.children(&mut cursor) {
+        if matches!(child.kind(), "super_interfaces" | "extends_interfaces") {
+            let mut child_cursor = child.walk();
+            for type_list in child
+                .named_children(&mut child_cursor)
+                .filter(|node| node.kind() == "type_list")
+            {
+                let mut type_cursor = type_list.walk();
+                supertypes.extend(
+                    type_list
+                        .named_children(&mut type_cursor)
+                        .filter_map(|type_node| type_name(source_code, type_node))
+                        .map(|name| Supertype {
+                            name,
+                            kind: SupertypeKind::Interface,
+                        }),
+                );
+            }
+        }
+    }
+
+    supertypes
+}
+
+// 型ノードから型引数を除いた型名を取得する関数（例: com.example.Base<T> -> com.example.Base）
+fn type_name(source_code: &str, type_node: tree_sitter::Node) -> Option<String> {
+    match type_node.kind() {
+        "type_identifier" => Some(source_code[type_node.byte_range()].to_string()),
+        "generic_type" => type_name(source_code, type_node.named_child(0)?),
+        "scoped_type_identifier" => {
+            let mut cursor = type_node.walk();
+            let segments: Vec<String> = type_node
+                .named_children(&mut cursor)
+                .filter_map(|child| type_name(source_code, child))
+                .collect();
+            (!segments.is_empty()).then(|| segments.join("."))
+        }
+        _ => None,
+    }
+}
+
+/// Javaのフロントエンド（`.java`ファイル）
+pub struct JavaFrontend;
+
+impl LanguageFrontend for JavaFrontend {
+    fn name(&self) -> &str {
+        "Java"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["java"]
+    }
+
+    fn extract_types(&self, file_path: &str, source_code: &str) -> Result<Vec<TypeDeclaration>> {
+        let tree = parse(source_code)?;
+        extract_types_from_tree(file_path, source_code, tree.root_node())
+    }
+
+    fn extract_constants(
+        &self,
+        _file_path: &str,
+        source_code: &str,
+    ) -> Result<Vec<ConstantDefinition>> {
+        let tree = parse(source_code)?;
+        extract_constants_from_tree(source_code, tree.root_node())
+    }
+
+    fn syntax_diagnostics(&self, file_path: &str, source_code: &str) -> Vec<Diagnostic> {
+        match parse(source_code) {
+            Ok(tree) => diagnostics::syntax_errors_near_annotations(
+                file_path,
+                source_code,
+                tree.root_node(),
+            ),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    // 1回のパースでまとめて抽出する
+    fn extract_file_facts(
+        &self,
+        file_path: &str,
+        source_code: &str,
+        annotations: &MappingAnnotations,
+    ) -> Result<FileFacts> {
+        extract_file_facts(file_path, source_code, annotations)
+    }
+}
@@ -0,0 +1,790 @@
+use anyhow::{Context, Result};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
+use tree_sitter::{Parser, Query, QueryCursor, StreamingIterator, Tree};
+
+use crate::annotation::{Annotation, AnnotationValue};
+use crate::cache::FileFacts;
+use crate::class_index::{Import, TypeScope};
+use crate::constants::{self, ConstantDefinition, ConstantExpr};
+use crate::diagnostics::{self, Diagnostic};
+use crate::frontend::{LanguageFrontend, MethodDeclaration, ParameterDeclaration, TypeDeclaration};
+use crate::mapping_annotations::MappingAnnotations;
+use crate::spring;
+use crate::{Supertype, SupertypeKind};
+
+fn create_parser() -> Result<Parser> {
+    let mut parser = Parser::new();
+    let language = tree_sitter_kotlin_sg::LANGUAGE;
+    parser
+        .set_language(&language.into())
+        .context("Kotlinパーサーの読み込みに失敗しました")?;
+    Ok(parser)
+}
+
+// スレッドごとにパーサーを1つだけ生成して使い回す
+thread_local! {
+    static PARSER: RefCell<Option<Parser>> = const { RefCell::new(None) };
+}
+
+fn parse(source_code: &str) -> Result<Tree> {
+    PARSER.with(|parser| {
+        let mut parser = parser.borrow_mut();
+        if parser.is_none() {
+            *parser = Some(create_parser()?);
+        }
+        parser
+            .as_mut()
+            .and_then(|parser| parser.parse(source_code, None))
+            .context("構文解析に失敗しました")
+    })
+}
+
+// クエリは初回にコンパイルし、以降はスレッド間で共有して使い回す
+fn create_query(query_source: &'static str) -> Result<&'static Query> {
+    static QUERIES: OnceLock<Mutex<HashMap<&'static str, &'static Query>>> = OnceLock::new();
+
+    let mut queries = QUERIES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(query) = queries.get(query_source) {
+        return Ok(query);
+    }
+
+    let language = tree_sitter_kotlin_sg::LANGUAGE;
+    let query = Query::new(&language.into(), query_source)
+        .with_context(|| format!("クエリのコンパイルに失敗しました: {}", query_source.trim()))?;
+    let query: &'static Query = Box::leak(Box::new(query));
+    queries.insert(query_source, query);
+    Ok(query)
+}
+
+// ファイルにコントローラーが含まれるか判定する関数（Kotlin用）
+fn contains_controller(
+    source_code: &str,
+    types: &[TypeDeclaration],
+    annotations: &MappingAnnotations,
+) -> bool {
+    // Simple string search for quick check before querying
+    annotations.may_appear_in(source_code) && spring::contains_controller(types, annotations)
+}
+
+// ファイル内のクラス宣言（クラス名とノード）を列挙する関数（Kotlin用）
+fn find_class_declarations<'tree>(
+    source_code: &str,
+    root_node: tree_sitter::Node<'tree>,
+) -> Result<Vec<(String, tree_sitter::Node<'tree>)>> {
+    let query_source = r#"
+        (class_declaration
+            (type_identifier) @class_name) @class
+    "#;
+
+    let query = create_query(query_source)?;
+
+    let mut query_cursor = QueryCursor::new();
+    let mut matches = query_cursor.matches(query, root_node, source_code.as_bytes());
+
+    let mut classes = Vec::new();
+
+    while let Some(m) = matches.next() {
+        let mut class_name = "";
+        let mut class_node = None;
+
+        for capture in m.captures {
+            let capture_name = &query.capture_names()[capture.index as usize];
+            match *capture_name {
+                "class_name" => class_name = &source_code[capture.node.byte_range()],
+                "class" => class_node = Some(capture.node),
+                _ => {}
+            }
+        }
+
+        if let Some(class_node) = class_node {
+            classes.push((class_name.to_string(), class_node));
+        }
+    }
+
+    Ok(classes)
+}
+
+// class_declarationがinterfaceか判定する関数（Kotlinではinterfaceもclass_declarationになる）
+fn is_interface(class_node: tree_sitter::Node) -> bool {
+    let mut cursor = class_node.walk();
+    let is_interface = class_node
+        .children(&mut cursor)
+        .any(|child| child.kind() == "interface");
+    is_interface
+}
+
+// クラス宣言に付与されたアノテーションを列挙する関数
+// tree-sitter-kotlinは定数参照を引数に持つトップレベルのアノテーションを、クラス宣言の直前の
+// prefix_expressionとして解釈することがあるため、その場合はそちらからも収集する
+fn class_annotations(class_node: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    let mut annotations = Vec::new();
+
+    // 別の式（クラス宣言を誤って解釈したinfix_expressionなど）を含む場合は、そちらに付与されたアノテーションなので対象外とする
+    let mut detached = Vec::new();
+    let mut pending = Vec::new();
+    if let Some(previous) = class_node
+        .prev_named_sibling()
+        .filter(|previous| previous.kind() == "prefix_expression")
+    {
+        pending.push(previous);
+    }
+    while let Some(prefix_expression) = pending.pop() {
+        let mut cursor = prefix_expression.walk();
+        for child in prefix_expression.named_children(&mut cursor) {
+            match child.kind() {
+                "annotation" => detached.push(child),
+                "prefix_expression" => pending.push(child),
+                "parenthesized_expression" => {}
+                _ => {
+                    detached.clear();
+                    pending.clear();
+                    break;
+                }
+            }
+        }
+    }
+    annotations.extend(detached);
+
+    let mut cursor = class_node.walk();
+    for child in class_node.named_children(&mut cursor) {
+        if child.kind() == "modifiers" {
+            let mut modifiers_cursor = child.walk();
+            annotations.extend(
+                child
+                    .named_children(&mut modifiers_cursor)
+                    .filter(|modifier| modifier.kind() == "annotation"),
+            );
+        }
+    }
+
+    annotations
+}
+
+// メソッドがクラスの直接のメンバーか判定する関数（ネストしたクラスのメソッドを除く・Kotlin用）
+fn is_member_of(method_node: tree_sitter::Node, class_node: tree_sitter::Node) -> bool {
+    let mut current = method_node.parent();
+    while let Some(parent) = current {
+        if matches!(
+            parent.kind(),
+            "class_declaration" | "object_declaration" | "companion_object" | "object_literal"
+        ) {
+            return parent.id() == class_node.id();
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+// パース済みの構文木からクラス・インターフェース宣言を、アノテーションとメソッドを含めて抽出する関数（Kotlin用）
+fn extract_types_from_tree(
+    file_path: &str,
+    source_code: &str,
+    root_node: tree_sitter::Node,
+) -> Result<Vec<TypeDeclaration>> {
+    let scope = TypeScope {
+        file_path: file_path.to_string(),
+        package: extract_package_name(source_code, root_node),
+        imports: extract_imports(source_code, root_node),
+        static_imports: Vec::new(),
+        enclosing_class: None,
+    };
+
+    find_class_declarations(source_code, root_node)?
+        .into_iter()
+        .map(|(class_name, class_node)| {
+            Ok(TypeDeclaration {
+                name: class_name,
+                scope: TypeScope {
+                    enclosing_class: enclosing_type_name(source_code, class_node),
+                    ..scope.clone()
+                },
+                byte_range: (class_node.start_byte(), class_node.end_byte()),
+                is_interface: is_interface(class_node),
+                supertypes: extract_inheritance_info(source_code, class_node),
+                annotations: class_annotations(class_node)
+                    .into_iter()
+                    .filter_map(|annotation| to_annotation(source_code, annotation, &scope.imports))
+                    .collect(),
+                methods: extract_methods(source_code, class_node, &scope.imports)?,
+            })
+        })
+        .collect()
+}
+
+// クラスの直接のメンバーである関数を抽出する関数（Kotlin用）
+fn extract_methods(
+    source_code: &str,
+    class_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Result<Vec<MethodDeclaration>> {
+    let query_source = r#"
+        (function_declaration) @method
+    "#;
+
+    let query = create_query(query_source)?;
+    let mut query_cursor = QueryCursor::new();
+    let mut matches = query_cursor.matches(query, class_node, source_code.as_bytes());
+
+    let mut methods = Vec::new();
+
+    while let Some(m) = matches.next() {
+        for capture in m.captures {
+            let method_node = capture.node;
+
+            // ネストしたクラスのメソッドは、そのクラスとして別に抽出する
+            if !is_member_of(method_node, class_node) {
+                continue;
+            }
+
+            let mut cursor = method_node.walk();
+            let Some(name_node) = method_node
+                .named_children(&mut cursor)
+                .find(|child| child.kind() == "simple_identifier")
+            else {
+                continue;
+            };
+
+            methods.push(MethodDeclaration {
+                name: source_code[name_node.byte_range()].to_string(),
+                line_range: (
+                    method_node.start_position().row + 1,
+                    method_node.end_position().row + 1,
+                ),
+                annotations: modifier_annotations(source_code, method_node, imports),
+                parameters: extract_parameters(source_code, method_node, imports),
+            });
+        }
+    }
+
+    Ok(methods)
+}
+
+// 関数の引数を抽出する関数（Kotlin用）
+fn extract_parameters(
+    source_code: &str,
+    method_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Vec<ParameterDeclaration> {
+    let mut cursor = method_node.walk();
+    let Some(parameters) = method_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "function_value_parameters")
+    else {
+        return Vec::new();
+    };
+
+    let mut declarations = Vec::new();
+    let mut annotations = Vec::new();
+
+    let mut parameters_cursor = parameters.walk();
+    for child in parameters.named_children(&mut parameters_cursor) {
+        match child.kind() {
+            // 引数のアノテーションは、直後のparameterとは兄弟ノードになる
+            "parameter_modifiers" => {
+                let mut modifiers_cursor = child.walk();
+                annotations.extend(
+                    child
+                        .named_children(&mut modifiers_cursor)
+                        .filter(|modifier| modifier.kind() == "annotation")
+                        .filter_map(|annotation| to_annotation(source_code, annotation, imports)),
+                );
+            }
+            "parameter" => {
+                let annotations = std::mem::take(&mut annotations);
+                let Some(name_node) = child.named_child(0) else {
+                    continue;
+                };
+                let Some(type_node) = name_node.next_named_sibling() else {
+                    continue;
+                };
+                declarations.push(ParameterDeclaration {
+                    name: source_code[name_node.byte_range()].to_string(),
+                    param_type: source_code[type_node.byte_range()].to_string(),
+                    annotations,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    declarations
+}
+
+// 宣言の修飾子に含まれるアノテーションを抽出する関数（Kotlin用）
+fn modifier_annotations(
+    source_code: &str,
+    declaration_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Vec<Annotation> {
+    let mut cursor = declaration_node.walk();
+    let Some(modifiers) = declaration_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "modifiers")
+    else {
+        return Vec::new();
+    };
+
+    let mut modifiers_cursor = modifiers.walk();
+    modifiers
+        .named_children(&mut modifiers_cursor)
+        .filter(|modifier| modifier.kind() == "annotation")
+        .filter_map(|annotation| to_annotation(source_code, annotation, imports))
+        .collect()
+}
+
+// アノテーションノードを言語によらない形に変換する関数（Kotlin用）
+// 名前なしの引数は"value"として扱い、varargとして複数指定されている場合は配列にまとめる
+fn to_annotation(
+    source_code: &str,
+    annotation_node: tree_sitter::Node,
+    imports: &[Import],
+) -> Option<Annotation> {
+    let mut cursor = annotation_node.walk();
+    let children: Vec<_> = annotation_node.named_children(&mut cursor).collect();
+    let invocation = children
+        .iter()
+        .copied()
+        .find(|child| child.kind() == "constructor_invocation");
+    let type_node = match invocation {
+        Some(invocation) => invocation.named_child(0)?,
+        None => children
+            .iter()
+            .copied()
+            .find(|child| child.kind() == "user_type")?,
+    };
+    let mut annotation = Annotation::new(&user_type_name(source_code, type_node)?, imports);
+
+    let Some(invocation) = invocation else {
+        // prefix_expressionとして解釈されたアノテーションは、直後の括弧式が名前なしの引数になる
+        if let Some(argument) = annotation_node
+            .next_named_sibling()
+            .filter(|sibling| sibling.kind() == "parenthesized_expression")
+            .and_then(|sibling| sibling.named_child(0))
+        {
+            annotation.args.insert(
+                "value".to_string(),
+                to_annotation_value(source_code, argument),
+            );
+        }
+        return Some(annotation);
+    };
+
+    let mut positional = Vec::new();
+    for (key, value) in value_arguments(source_code, invocation) {
+        match key {
+            Some(key) => {
+                annotation.args.insert(key, value);
+            }
+            None => positional.push(value),
+        }
+    }
+    match positional.len() {
+        0 => {}
+        1 => {
+            annotation
+                .args
+                .insert("value".to_string(), positional.remove(0));
+        }
+        _ => {
+            annotation
+                .args
+                .insert("value".to_string(), AnnotationValue::Array(positional));
+        }
+    }
+
+    Some(annotation)
+}
+
+// 呼び出し（constructor_invocation・call_expression）の引数を、名前（名前なしの場合はNone）と値の組で返す関数
+fn value_arguments(
+    source_code: &str,
+    invocation_node: tree_sitter::Node,
+) -> Vec<(Option<String>, AnnotationValue)> {
+    let mut cursor = invocation_node.walk();
+    let value_arguments = invocation_node
+        .named_children(&mut cursor)
+        .find_map(|child| match child.kind() {
+            "value_arguments" => Some(child),
+            "call_suffix" => {
+                let mut suffix_cursor = child.walk();
+                let arguments = child
+                    .named_children(&mut suffix_cursor)
+                    .find(|suffix| suffix.kind() == "value_arguments");
+                arguments
+            }
+            _ => None,
+        });
+    let Some(value_arguments) = value_arguments else {
+        return Vec::new();
+    };
+
+    let mut arguments = Vec::new();
+    let mut arguments_cursor = value_arguments.walk();
+    for argument in value_arguments.named_children(&mut arguments_cursor) {
+        if argument.kind() != "value_argument" {
+            continue;
+        }
+
+        let mut argument_cursor = argument.walk();
+        let is_named = argument
+            .children(&mut argument_cursor)
+            .any(|child| child.kind() == "=");
+        let Some(value_node) = argument
+            .named_child_count()
+            .checked_sub(1)
+            .and_then(|index| argument.named_child(index))
+        else {
+            continue;
+        };
+
+        let key = is_named
+            .then(|| argument.named_child(0))
+            .flatten()
+            .map(|key_node| source_code[key_node.byte_range()].to_string());
+        arguments.push((key, to_annotation_value(source_code, value_node)));
+    }
+
+    arguments
+}
+
+// アノテーションの引数の値を変換する関数（Kotlin用、`[...]`と`arrayOf(...)`は配列として扱う）
+fn to_annotation_value(source_code: &str, value_node: tree_sitter::Node) -> AnnotationValue {
+    match value_node.kind() {
+        "collection_literal" => {
+            let mut cursor = value_node.walk();
+            AnnotationValue::Array(
+                value_node
+                    .named_children(&mut cursor)
+                    .filter(|element| element.kind() != "comment")
+                    .map(|element| to_annotation_value(source_code, element))
+                    .collect(),
+            )
+        }
+        "call_expression" if is_array_of(source_code, value_node) => AnnotationValue::Array(
+            value_arguments(source_code, value_node)
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect(),
+        ),
+        _ => AnnotationValue::Expr {
+            text: source_code[value_node.byte_range()].to_string(),
+            expr: to_constant_expr(source_code, value_node),
+        },
+    }
+}
+
+// arrayOf(...)の呼び出しか判定する関数
+fn is_array_of(source_code: &str, call_node: tree_sitter::Node) -> bool {
+    call_node
+        .named_child(0)
+        .is_some_and(|callee| &source_code[callee.byte_range()] == "arrayOf")
+}
+
+// キャッシュの単位となる、ファイル単独で決まる情報をまとめて抽出する関数（パースは1回だけ）
+fn extract_file_facts(
+    file_path: &str,
+    source_code: &str,
+    annotations: &MappingAnnotations,
+) -> Result<FileFacts> {
+    let tree = parse(source_code)?;
+    let root_node = tree.root_node();
+    let types = extract_types_from_tree(file_path, source_code, root_node)?;
+
+    Ok(FileFacts {
+        has_request_mapping: contains_controller(source_code, &types, annotations),
+        constants: extract_constants_from_tree(source_code, root_node)?,
+        classes: types.iter().map(TypeDeclaration::class_entry).collect(),
+        diagnostics: diagnostics::syntax_errors_near_annotations(file_path, source_code, root_node),
+    })
+}
+
+// 式ノードを定数式に変換する関数（文字列リテラル・文字列テンプレート・定数参照・+による結合に対応）
+fn to_constant_expr(source_code: &str, node: tree_sitter::Node) -> Option<ConstantExpr> {
+    match node.kind() {
+        "string_literal" => {
+            let mut parts = Vec::new();
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                let text = &source_code[child.byte_range()];
+                match child.kind() {
+                    "string_content" => parts.push(ConstantExpr::Literal(text.to_string())),
+                    "escape_sequence" => {
+                        parts.push(ConstantExpr::Literal(constants::unescape(text)))
+                    }
+                    "interpolated_identifier" => {
+                        parts.push(ConstantExpr::Reference(text.to_string()))
+                    }
+                    "interpolated_expression" => {
+                        parts.push(to_constant_expr(source_code, child.named_child(0)?)?)
+                    }
+                    _ => return None,
+                }
+            }
+
+            match parts.len() {
+                0 => Some(ConstantExpr::Literal("".to_string())),
+                1 => parts.pop(),
+                _ => Some(ConstantExpr::Concat(parts)),
+            }
+        }
+        "simple_identifier" | "navigation_expression" => {
+            let text = &source_code[node.byte_range()];
+            Some(ConstantExpr::Reference(
+                text.chars().filter(|c| !c.is_whitespace()).collect(),
+            ))
+        }
+        "parenthesized_expression" => to_constant_expr(source_code, node.named_child(0)?),
+        "additive_expression" => {
+            let mut cursor = node.walk();
+            let is_plus = node.children(&mut cursor).any(|child| child.kind() == "+");
+            if !is_plus {
+                return None;
+            }
+            let left = to_constant_expr(source_code, node.named_child(0)?)?;
+            let right = to_constant_expr(source_code, node.named_child(1)?)?;
+            Some(ConstantExpr::Concat(vec![left, right]))
+        }
+        _ => None,
+    }
+}
+
+// パース済みの構文木から定数定義を抽出する関数
+fn extract_constants_from_tree(
+    source_code: &str,
+    root_node: tree_sitter::Node,
+) -> Result<Vec<ConstantDefinition>> {
+    // Simple string search for quick check before querying
+    if !source_code.contains("const") {
+        return Ok(Vec::new());
+    }
+
+    let query_source = r#"
+        (property_declaration
+            (modifiers
+                (property_modifier) @modifier
+                (#eq? @modifier "const"))
+            (variable_declaration
+                (simple_identifier) @name)) @property
+    "#;
+
+    let query = create_query(query_source)?;
+    let mut query_cursor = QueryCursor::new();
+    let mut matches = query_cursor.matches(query, root_node, source_code.as_bytes());
+
+    let package = extract_package_name(source_code, root_node);
+    let imports = extract_imports(source_code, root_node);
+    let mut definitions = Vec::new();
+
+    while let Some(m) = matches.next() {
+        let mut name = "";
+        let mut property_node = None;
+
+        for capture in m.captures {
+            let capture_name = &query.capture_names()[capture.index as usize];
+            match *capture_name {
+                "name" => name = &source_code[capture.node.byte_range()],
+                "property" => property_node = Some(capture.node),
+                _ => {}
+            }
+        }
+
+        let Some(property_node) = property_node else {
+            continue;
+        };
+
+        // 初期化式は"="の直後のノード
+        let mut cursor = property_node.walk();
+        let value_node = property_node
+            .children(&mut cursor)
+            .skip_while(|child| child.kind() != "=")
+            .nth(1);
+
+        if let Some(expr) = value_node.and_then(|node| to_constant_expr(source_code, node)) {
+            definitions.push(ConstantDefinition {
+                package: package.clone(),
+                owner: enclosing_type_name(source_code, property_node),
+                name: name.to_string(),
+                expr,
+                imports: imports.clone(),
+            });
+        }
+    }
+
+    Ok(definitions)
+}
+
+// package宣言からパッケージ名を取得する関数（Kotlin用）
+fn extract_package_name(source_code: &str, root_node: tree_sitter::Node) -> Option<String> {
+    let mut cursor = root_node.walk();
+    let package_node = root_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "package_header")?;
+    let name_node = package_node.named_child(0)?;
+
+    Some(source_code[name_node.byte_range()].to_string())
+}
+
+// import宣言を取得する関数（Kotlin用、`as`による別名を含む）
+fn extract_imports(source_code: &str, root_node: tree_sitter::Node) -> Vec<Import> {
+    let mut import_headers = Vec::new();
+    let mut cursor = root_node.walk();
+    for child in root_node.named_children(&mut cursor) {
+        match child.kind() {
+            "import_list" => {
+                let mut list_cursor = child.walk();
+                import_headers.extend(
+                    child
+                        .named_children(&mut list_cursor)
+                        .filter(|header| header.kind() == "import_header"),
+                );
+            }
+            "import_header" => import_headers.push(child),
+            _ => {}
+        }
+    }
+
+    import_headers
+        .into_iter()
+        .filter_map(|import_header| {
+            let mut header_cursor = import_header.walk();
+            let children: Vec<_> = import_header.named_children(&mut header_cursor).collect();
+
+            let name_node = children.iter().find(|child| child.kind() == "identifier")?;
+            let alias = children
+                .iter()
+                .find(|child| child.kind() == "import_alias")
+                .and_then(|alias_node| alias_node.named_child(0))
+                .map(|alias_node| source_code[alias_node.byte_range()].to_string());
+            Some(Import {
+                path: source_code[name_node.byte_range()].to_string(),
+                wildcard: children
+                    .iter()
+                    .any(|child| child.kind() == "wildcard_import"),
+                alias,
+            })
+        })
+        .collect()
+}
+
+// ノードを囲む型名を取得する関数（Kotlin用、companion objectは外側のクラスに属するものとして扱う）
+fn enclosing_type_name(source_code: &str, node: tree_sitter::Node) -> Option<String> {
+    let mut names = Vec::new();
+    let mut current = node.parent();
+
+    while let Some(parent) = current {
+        if matches!(parent.kind(), "class_declaration" | "object_declaration") {
+            let mut cursor = parent.walk();
+            let name_node = parent
+                .named_children(&mut cursor)
+                .find(|child| child.kind() == "type_identifier");
+            if let Some(name_node) = name_node {
+                names.push(&source_code[name_node.byte_range()]);
+            }
+        }
+        current = parent.parent();
+    }
+
+    if names.is_empty() {
+        return None;
+    }
+
+    names.reverse();
+    Some(names.join("."))
+}
+
+// 継承情報を抽出する関数（Kotlin用）
+// delegation_specifierのうち、コンストラクタ呼び出しを伴うものを親クラス、
+// それ以外（`by`による委譲を含む）をインターフェースとして扱う
+fn extract_inheritance_info(source_code: &str, class_node: tree_sitter::Node) -> Vec<Supertype> {
+    let mut cursor = class_node.walk();
+    class_node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "delegation_specifier")
+        .filter_map(|specifier| {
+            let mut specifier_cursor = specifier.walk();
+            let (type_node, kind) =
+                specifier
+                    .named_children(&mut specifier_cursor)
+                    .find_map(|child| match child.kind() {
+                        "constructor_invocation" => {
+                            Some((child.named_child(0)?, SupertypeKind::Class))
+                        }
+                        "explicit_delegation" => {
+                            Some((child.named_child(0)?, SupertypeKind::Interface))
+                        }
+                        "user_type" => Some((child, SupertypeKind::Interface)),
+                        _ => None,
+                    })?;
+            let name = user_type_name(source_code, type_node)?;
+            Some(Supertype { name, kind })
+        })
+        .collect()
+}
+
+// user_typeから型引数を除いた型名を取得する関数（例: com.example.Base<T> -> com.example.Base）
+fn user_type_name(source_code: &str, type_node: tree_sitter::Node) -> Option<String> {
+    if type_node.kind() != "user_type" {
+        return None;
+    }
+
+    let mut cursor = type_node.walk();
+    let segments: Vec<&str> = type_node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "type_identifier")
+        .map(|child| &source_code[child.byte_range()])
+        .collect();
+    (!segments.is_empty()).then(|| segments.join("."))
+}
+
+/// Kotlinのフロントエンド（`.kt`ファイル）
+pub struct KotlinFrontend;
+
+impl LanguageFrontend for KotlinFrontend {
+    fn name(&self) -> &str {
+        "Kotlin"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["kt"]
+    }
+
+    fn extract_types(&self, file_path: &str, source_code: &str) -> Result<Vec<TypeDeclaration>> {
+        let tree = parse(source_code)?;
+        extract_types_from_tree(file_path, source_code, tree.root_node())
+    }
+
+    fn extract_constants(
+        &self,
+        _file_path: &str,
+        source_code: &str,
+    ) -> Result<Vec<ConstantDefinition>> {
+        let tree = parse(source_code)?;
+        extract_constants_from_tree(source_code, tree.root_node())
+    }
+
+    fn syntax_diagnostics(&self, file_path: &str, source_code: &str) -> Vec<Diagnostic> {
+        match parse(source_code) {
+            Ok(tree) => diagnostics::syntax_errors_near_annotations(
+                file_path,
+                source_code,
+                tree.root_node(),
+            ),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    // 1回のパースでまとめて抽出する
+    fn extract_file_facts(
+        &self,
+        file_path: &str,
+        source_code: &str,
+        annotations: &MappingAnnotations,
+    ) -> Result<FileFacts> {
+        extract_file_facts(file_path, source_code, annotations)
+    }
+}
@@ -1,2 +1,2 @@
 pub mod java;
-pub mod kotlin;
\ No newline at end of file
+pub mod kotlin;
@@ -0,0 +1,188 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// モジュール内のソースディレクトリ
+const SOURCE_DIRS: &[&str] = &["src/main/java", "src/main/kotlin"];
+
+/// スキャンするディレクトリと、そのディレクトリが属するモジュール
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRoot {
+    pub path: String,
+    /// Gradle・Mavenのモジュール名（分からない場合はNone）
+    pub module: Option<String>,
+}
+
+impl SourceRoot {
+    pub fn new(path: impl Into<String>) -> Self {
+        SourceRoot {
+            path: path.into(),
+            module: None,
+        }
+    }
+}
+
+/// Gradle・Mavenのマルチモジュールプロジェクトから、各モジュールのソースディレクトリを探す
+///
+/// `settings.gradle(.kts)`の`include`か`pom.xml`の`<modules>`からモジュールを列挙し、
+/// ルートのプロジェクトを含む各モジュールの`src/main/java`・`src/main/kotlin`を返す。
+/// ビルドファイルがない場合は空を返す。
+///
+/// モジュール名は、Gradleは`include`で指定したプロジェクトのパス（先頭の`:`を除く。例: `common:core`）、
+/// Mavenはルートからのディレクトリ（例: `common/core`）。ルートのプロジェクトはディレクトリ名とする。
+pub fn discover_source_roots(project_dir: &Path) -> Result<Vec<SourceRoot>> {
+    let modules = if let Some(settings) = find_gradle_settings(project_dir) {
+        gradle_modules(&settings)?
+    } else if project_dir.join("pom.xml").is_file() {
+        let mut modules = Vec::new();
+        maven_modules(project_dir, "", &mut modules);
+        modules
+    } else {
+        return Ok(Vec::new());
+    };
+
+    let root_name = project_dir
+        .canonicalize()
+        .ok()
+        .and_then(|dir| {
+            dir.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| project_dir.to_string_lossy().to_string());
+
+    let mut roots = Vec::new();
+    for (name, dir) in std::iter::once((root_name, String::new())).chain(modules) {
+        for source_dir in SOURCE_DIRS {
+            let path = project_dir.join(&dir).join(source_dir);
+            if path.is_dir() {
+                roots.push(SourceRoot {
+                    path: path.to_string_lossy().to_string(),
+                    module: Some(name.clone()),
+                });
+            }
+        }
+    }
+    Ok(roots)
+}
+
+/// ディレクトリがGradle・Mavenのプロジェクトのルートか
+pub fn is_project_root(dir: &Path) -> bool {
+    find_gradle_settings(dir).is_some() || dir.join("pom.xml").is_file()
+}
+
+fn find_gradle_settings(project_dir: &Path) -> Option<PathBuf> {
+    ["settings.gradle.kts", "settings.gradle"]
+        .iter()
+        .map(|name| project_dir.join(name))
+        .find(|path| path.is_file())
+}
+
+// settings.gradle(.kts)の`include`から（モジュール名, ディレクトリ）を列挙する
+fn gradle_modules(settings: &Path) -> Result<Vec<(String, String)>> {
+    let content = fs::read_to_string(settings)
+        .with_context(|| format!("{}の読み込みに失敗しました", settings.display()))?;
+
+    Ok(parse_gradle_includes(&content)
+        .into_iter()
+        .map(|project_path| {
+            let name = project_path.trim_start_matches(':').to_string();
+            let dir = name.replace(':', "/");
+            (name, dir)
+        })
+        .collect())
+}
+
+/// `include ':api', ':web'`・`include(":api")`のような宣言からプロジェクトのパスを取り出す
+fn parse_gradle_includes(content: &str) -> Vec<String> {
+    let lines: Vec<&str> = content
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or("").trim())
+        .collect();
+
+    let mut includes = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(rest) = lines[i].strip_prefix("include") else {
+            i += 1;
+            continue;
+        };
+        if !(rest.starts_with(' ') || rest.starts_with('(')) {
+            i += 1;
+            continue;
+        }
+
+        // 括弧で囲まれている場合は閉じ括弧まで、それ以外は行末が`,`の間は次の行に続く
+        let mut statement = rest.to_string();
+        let parenthesized = rest.trim_start().starts_with('(');
+        while i + 1 < lines.len()
+            && if parenthesized {
+                !statement.contains(')')
+            } else {
+                statement.trim_end().ends_with(',')
+            }
+        {
+            i += 1;
+            statement.push_str(lines[i]);
+        }
+        includes.extend(quoted_strings(&statement));
+        i += 1;
+    }
+    includes
+}
+
+// 文字列リテラル（'...'・"..."）の中身を列挙する
+fn quoted_strings(text: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\'' || c == '"' {
+            let value: String = chars.by_ref().take_while(|&next| next != c).collect();
+            strings.push(value);
+        }
+    }
+    strings
+}
+
+// pom.xmlの`<module>`から（モジュール名, ディレクトリ）を再帰的に列挙する
+fn maven_modules(project_dir: &Path, base_dir: &str, modules: &mut Vec<(String, String)>) {
+    let pom = project_dir.join(base_dir).join("pom.xml");
+    let Ok(content) = fs::read_to_string(pom) else {
+        return;
+    };
+
+    for module in xml_elements(&content, "module") {
+        let dir = if base_dir.is_empty() {
+            module.trim_end_matches('/').to_string()
+        } else {
+            format!("{}/{}", base_dir, module.trim_end_matches('/'))
+        };
+        // 同じモジュールを複数回列挙しない（プロファイルごとに<modules>がある場合など）
+        if modules.iter().any(|(_, existing)| *existing == dir) {
+            continue;
+        }
+        modules.push((dir.clone(), dir.clone()));
+        maven_modules(project_dir, &dir, modules);
+    }
+}
+
+// `<name>...</name>`の中身を列挙する（コメントは除く）
+fn xml_elements(content: &str, name: &str) -> Vec<String> {
+    let mut content = content.to_string();
+    while let Some(start) = content.find("<!--") {
+        let end = content[start..]
+            .find("-->")
+            .map(|end| start + end + 3)
+            .unwrap_or(content.len());
+        content.replace_range(start..end, "");
+    }
+
+    let open = format!("<{}>", name);
+    let close = format!("</{}>", name);
+    content
+        .split(&open)
+        .skip(1)
+        .filter_map(|rest| rest.split(&close).next())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
@@ -0,0 +1,305 @@
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::diagnostics::{Diagnostic, DiagnosticKind};
+use crate::frontend::{FrontendRegistry, LanguageFrontend};
+
+// ツール・IDEのディレクトリ（どの階層でもスキャンしない）
+const TOOL_DIRS: &[&str] = &[".git", ".gradle", ".idea", "node_modules"];
+
+// ビルドの出力先のディレクトリ（ビルドファイルやsrcディレクトリと同じ階層にある場合だけスキャンしない）
+//
+// `com/example/build`のようなパッケージのディレクトリは除外しない。
+const BUILD_OUTPUT_DIRS: &[&str] = &["build", "out", "target"];
+const PROJECT_MARKERS: &[&str] = &[
+    "src",
+    "build.gradle",
+    "build.gradle.kts",
+    "settings.gradle",
+    "settings.gradle.kts",
+    "pom.xml",
+];
+
+/// スキャンするファイルの絞り込み（パターンはスキャンのルートからの相対パスのglob）
+///
+/// ファイル自体か、ファイルを含むディレクトリのどれかがパターンに一致すれば一致とみなすため、
+/// `build`・`**/generated`のようにディレクトリを指定できる。
+#[derive(Debug, Clone)]
+pub struct SourceFilter {
+    // 指定されていない場合はすべてのファイルが対象
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+    // ビルドの出力先・ツールのディレクトリを除外するか
+    default_excludes: bool,
+    // .gitignoreで無視されているファイルを除外するか
+    gitignore: bool,
+}
+
+impl Default for SourceFilter {
+    fn default() -> Self {
+        SourceFilter {
+            include: None,
+            exclude: None,
+            default_excludes: true,
+            gitignore: false,
+        }
+    }
+}
+
+impl SourceFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> Result<Self> {
+        Ok(SourceFilter {
+            include: build_glob_set(include)?,
+            exclude: build_glob_set(exclude)?,
+            ..SourceFilter::default()
+        })
+    }
+
+    /// ビルドの出力先（`build`・`out`・`target`）やツールのディレクトリ（`.git`・`node_modules`など）を除外するか
+    pub fn default_excludes(mut self, yes: bool) -> Self {
+        self.default_excludes = yes;
+        self
+    }
+
+    /// .gitignore（と.git/info/exclude）で無視されているファイルを除外するか
+    pub fn gitignore(mut self, yes: bool) -> Self {
+        self.gitignore = yes;
+        self
+    }
+
+    /// ルートからの相対パスのファイルをスキャンするか
+    pub fn is_included(&self, relative_path: &Path) -> bool {
+        let matches = |glob_set: &GlobSet| {
+            relative_path
+                .ancestors()
+                .filter(|path| !path.as_os_str().is_empty())
+                .any(|path| glob_set.is_match(path))
+        };
+
+        self.include.as_ref().is_none_or(matches) && !self.exclude.as_ref().is_some_and(matches)
+    }
+
+    // ディレクトリの中を辿るか（除外するディレクトリは中身を列挙しない）
+    fn should_descend(&self, dir: &Path, relative_path: &Path) -> bool {
+        if self
+            .exclude
+            .as_ref()
+            .is_some_and(|exclude| exclude.is_match(relative_path))
+        {
+            return false;
+        }
+        if !self.default_excludes {
+            return true;
+        }
+
+        let Some(name) = dir.file_name().and_then(|name| name.to_str()) else {
+            return true;
+        };
+        if TOOL_DIRS.contains(&name) {
+            return false;
+        }
+        !(BUILD_OUTPUT_DIRS.contains(&name)
+            && dir.parent().is_some_and(|parent| {
+                PROJECT_MARKERS
+                    .iter()
+                    .any(|marker| parent.join(marker).exists())
+            }))
+    }
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        // `/`を含まないパターンは、どの階層のファイル・ディレクトリにも一致させる
+        let glob = if pattern.contains('/') {
+            pattern.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{}", pattern)
+        };
+        builder.add(
+            GlobBuilder::new(&glob)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("パターンが不正です: {}", pattern))?,
+        );
+    }
+    Ok(Some(builder.build()?))
+}
+
+/// 列挙したソースファイルと、列挙中に見つかった問題
+#[derive(Debug, Default)]
+pub(crate) struct SourceFiles {
+    pub files: Vec<String>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+// ディレクトリ内のソースファイルを並列に解析する（結果はファイルパス順に並べる）
+//
+// 読み込みや解析に失敗したファイルは診断結果として報告し、残りのファイルの解析を続ける。
+pub(crate) fn parse_source_files<T: Send>(
+    dir_path: &str,
+    frontends: &FrontendRegistry,
+    diagnostics: &mut Vec<Diagnostic>,
+    parse: impl Fn(&dyn LanguageFrontend, &str, &str) -> Result<T> + Sync,
+) -> Vec<T> {
+    let source_files =
+        collect_source_files(&[dir_path.to_string()], &SourceFilter::default(), frontends);
+    diagnostics.extend(source_files.diagnostics);
+
+    let parsed: Vec<Result<T, Diagnostic>> = source_files
+        .files
+        .par_iter()
+        .map(|file_path| {
+            let source_code = fs::read_to_string(file_path).map_err(|e| {
+                Diagnostic::error(
+                    DiagnosticKind::ReadError,
+                    file_path,
+                    format!("Failed to read file: {}", e),
+                )
+            })?;
+            frontends
+                .for_file(file_path)
+                .and_then(|frontend| parse(frontend, file_path, &source_code))
+                .map_err(|e| {
+                    Diagnostic::error(DiagnosticKind::ParseError, file_path, format!("{:#}", e))
+                })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for result in parsed {
+        match result {
+            Ok(value) => results.push(value),
+            Err(diagnostic) => diagnostics.push(diagnostic),
+        }
+    }
+    results
+}
+
+// ディレクトリ内のソースファイル（フロントエンドが対応している拡張子のファイル）をディレクトリの指定順・パス順に列挙する
+//
+// シンボリックリンクは辿るが、祖先のディレクトリを指すリンク（循環）は辿らずに警告する。
+// シンボリックリンクなどで同じファイルに複数のパスから辿れる場合は最初のパスだけを使う。
+pub(crate) fn collect_source_files(
+    dir_paths: &[String],
+    filter: &SourceFilter,
+    frontends: &FrontendRegistry,
+) -> SourceFiles {
+    let mut source_files = SourceFiles::default();
+    let mut seen_files = HashSet::new();
+    let mut seen_links = HashSet::new();
+
+    for dir_path in dir_paths {
+        let root = Path::new(dir_path).to_path_buf();
+        let dir_filter = filter.clone();
+        let walker = WalkBuilder::new(dir_path)
+            .standard_filters(false)
+            .git_ignore(filter.gitignore)
+            .git_exclude(filter.gitignore)
+            .parents(filter.gitignore)
+            .require_git(false)
+            .follow_links(true)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(move |entry| {
+                if !entry
+                    .file_type()
+                    .is_some_and(|file_type| file_type.is_dir())
+                {
+                    return true;
+                }
+                match entry.path().strip_prefix(&root) {
+                    Ok(relative_path) if !relative_path.as_os_str().is_empty() => {
+                        dir_filter.should_descend(entry.path(), relative_path)
+                    }
+                    _ => true,
+                }
+            })
+            .build();
+
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(error) => {
+                    // 同じリンクに複数のパスから辿れる場合も警告は1回だけにする
+                    let diagnostic = walk_diagnostic(dir_path, &error);
+                    if diagnostic.kind != DiagnosticKind::SymlinkCycle
+                        || seen_links.insert(link_path(Path::new(&diagnostic.file_path)))
+                    {
+                        source_files.diagnostics.push(diagnostic);
+                    }
+                    continue;
+                }
+            };
+            let path = entry.path();
+            if !entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+                || frontends.for_path(path).is_none()
+                || !path
+                    .strip_prefix(dir_path)
+                    .is_ok_and(|relative_path| filter.is_included(relative_path))
+            {
+                continue;
+            }
+
+            let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            if seen_files.insert(canonical_path) {
+                source_files.files.push(path.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    source_files
+}
+
+// シンボリックリンク自体の実際のパス（リンクを含むディレクトリだけを解決する）
+fn link_path(path: &Path) -> PathBuf {
+    match (path.parent().map(Path::canonicalize), path.file_name()) {
+        (Some(Ok(parent)), Some(name)) => parent.join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
+// ディレクトリの列挙中のエラーを診断結果に変換する
+fn walk_diagnostic(dir_path: &str, error: &ignore::Error) -> Diagnostic {
+    let mut error_path = None;
+    let mut current = error;
+    loop {
+        match current {
+            ignore::Error::WithPath { path, err } => {
+                error_path.get_or_insert(path.to_string_lossy().to_string());
+                current = err;
+            }
+            ignore::Error::WithDepth { err, .. } | ignore::Error::WithLineNumber { err, .. } => {
+                current = err;
+            }
+            _ => break,
+        }
+    }
+
+    match current {
+        ignore::Error::Loop { ancestor, child } => Diagnostic::warning(
+            DiagnosticKind::SymlinkCycle,
+            &child.to_string_lossy(),
+            format!(
+                "Symbolic link cycle detected: {} points to its ancestor {}",
+                child.display(),
+                ancestor.display()
+            ),
+        ),
+        _ => Diagnostic::error(
+            DiagnosticKind::ReadError,
+            error_path.as_deref().unwrap_or(dir_path),
+            format!("Failed to read directory entry: {}", current),
+        ),
+    }
+}
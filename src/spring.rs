@@ -0,0 +1,385 @@
+use crate::annotation::{Annotation, AnnotationValue};
+use crate::class_index::DeclaringClass;
+use crate::constants::{ConstantExpr, ConstantScope, ConstantTable};
+use crate::diagnostics::{Diagnostic, DiagnosticKind};
+use crate::frontend::{MethodDeclaration, TypeDeclaration};
+use crate::mapping_annotations::MappingAnnotations;
+use crate::{url_path, ClassMapping, Condition, Endpoint, Parameter, ScanContext};
+
+// Springの`RequestMethod`の定数
+const REQUEST_METHODS: &[&str] = &[
+    "GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS", "TRACE",
+];
+
+// エンドポイントのパラメータとして扱う引数のアノテーション
+const PARAMETER_ANNOTATIONS: &[&str] = &["PathVariable", "RequestBody", "RequestParam"];
+
+// ファイル内の宣言にコントローラーが含まれるか判定する関数（他のクラスに継承されているかは考慮しない）
+pub(crate) fn contains_controller(
+    types: &[TypeDeclaration],
+    annotations: &MappingAnnotations,
+) -> bool {
+    types.iter().any(|declaration| {
+        !declaration.is_interface
+            && (has_controller_annotation(declaration, annotations)
+                || has_method_mappings(declaration, annotations))
+    })
+}
+
+// コントローラーとして扱うクラスか判定する関数
+// インターフェースは実装クラスを通じてのみ扱う。クラスレベルのアノテーションがない場合は、
+// メソッドレベルのマッピングを持ち、他のクラスの親クラスとして使われていないものだけをコントローラーとみなす
+pub(crate) fn is_controller(declaration: &TypeDeclaration, context: &ScanContext) -> bool {
+    !declaration.is_interface
+        && (has_controller_annotation(declaration, context.annotations())
+            || (!context.is_extended_class(
+                &DeclaringClass::new(&declaration.scope, &declaration.name).qualified_name,
+            ) && has_method_mappings(declaration, context.annotations())))
+}
+
+// クラスレベルに@Controller/@RestController/@RequestMapping（または別名）が付与されているか判定する関数
+fn has_controller_annotation(
+    declaration: &TypeDeclaration,
+    annotations: &MappingAnnotations,
+) -> bool {
+    declaration
+        .annotations
+        .iter()
+        .any(|annotation| annotations.is_controller(annotation))
+}
+
+// メソッドレベルのマッピングアノテーションを持つか判定する関数
+fn has_method_mappings(declaration: &TypeDeclaration, annotations: &MappingAnnotations) -> bool {
+    declaration
+        .methods
+        .iter()
+        .any(|method| mapping_annotation(method, annotations).is_some())
+}
+
+// メソッドにマッピングアノテーションが付与されているか判定する関数
+pub(crate) fn has_mapping(method: &MethodDeclaration, annotations: &MappingAnnotations) -> bool {
+    mapping_annotation(method, annotations).is_some()
+}
+
+// メソッドのマッピングアノテーションを取得する関数（マッピング以外の@Overrideなどは対象外）
+fn mapping_annotation<'a>(
+    method: &'a MethodDeclaration,
+    annotations: &MappingAnnotations,
+) -> Option<&'a Annotation> {
+    method
+        .annotations
+        .iter()
+        .find(|annotation| annotations.is_method_mapping(annotation))
+}
+
+// クラスレベルの@RequestMappingの情報を取得する関数（クラスに@RequestMappingがなければNone）
+// 解決できなかった定数参照はdiagnosticsに追加する
+pub(crate) fn class_mapping(
+    declaration: &TypeDeclaration,
+    context: &ScanContext,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<ClassMapping> {
+    let annotation = declaration
+        .annotations
+        .iter()
+        .find(|annotation| context.annotations().is_request_mapping(annotation))?;
+
+    let mut resolver = ArgumentResolver::new(declaration, context.constants(), None, diagnostics);
+
+    Some(ClassMapping {
+        paths: path_argument(annotation)
+            .map(|value| resolver.paths(value))
+            .unwrap_or_default(),
+        consumes: resolver
+            .argument(annotation, "consumes")
+            .unwrap_or_default(),
+        produces: resolver
+            .argument(annotation, "produces")
+            .unwrap_or_default(),
+        headers: resolver.conditions(annotation, "headers"),
+        params: resolver.conditions(annotation, "params"),
+    })
+}
+
+// クラスで宣言されたメソッドのエンドポイントを、クラスレベルのマッピングと組み合わせて作成する関数
+// 親クラスから継承したエンドポイントの場合は、子クラスのマッピングを使う
+// 解決できなかった定数参照や、RequestMethodの定数以外のmethod属性はdiagnosticsに追加する
+pub(crate) fn endpoints(
+    declaration: &TypeDeclaration,
+    class_mapping: &ClassMapping,
+    context: &ScanContext,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<Endpoint> {
+    let class_name = &declaration.name;
+
+    let mut endpoints = Vec::new();
+
+    for method in &declaration.methods {
+        let Some(annotation) = mapping_annotation(method, context.annotations()) else {
+            continue;
+        };
+        let Some(mapping_type) = context.annotations().canonical(annotation) else {
+            continue;
+        };
+        let mut resolver = ArgumentResolver::new(
+            declaration,
+            context.constants(),
+            Some(method.line_range.0),
+            diagnostics,
+        );
+
+        // RequestMappingの場合はmethod属性を調べる（複数指定時はメソッドごとにエンドポイントを作成）
+        let http_methods = if mapping_type == "RequestMapping" {
+            resolver.request_mapping_methods(annotation)
+        } else {
+            // 他のマッピングタイプはそのままHTTPメソッドに変換
+            vec![mapping_type_to_http_method(mapping_type)]
+        };
+
+        // パスは定数参照や文字列結合を解決した値を使う
+        let paths = path_argument(annotation)
+            .map(|value| resolver.paths(value))
+            .unwrap_or_default();
+
+        // パスが指定されていない場合（空配列を含む）は、ベースパスのみを使用
+        let paths = if paths.is_empty() {
+            vec!["".to_string()]
+        } else {
+            paths
+        };
+        let base_paths: Vec<&str> = if class_mapping.paths.is_empty() {
+            vec![""]
+        } else {
+            class_mapping.paths.iter().map(String::as_str).collect()
+        };
+
+        // consumes/producesはメソッドレベルの指定がクラスレベルの指定より優先される
+        let consumes = resolver
+            .argument(annotation, "consumes")
+            .unwrap_or_else(|| class_mapping.consumes.clone());
+        let produces = resolver
+            .argument(annotation, "produces")
+            .unwrap_or_else(|| class_mapping.produces.clone());
+
+        let parameters = parameters(method);
+
+        // headers/paramsはクラスレベルの条件とメソッドレベルの条件を組み合わせる
+        let mut headers = class_mapping.headers.clone();
+        headers.extend(resolver.conditions(annotation, "headers"));
+        let mut params = class_mapping.params.clone();
+        params.extend(resolver.conditions(annotation, "params"));
+
+        // HTTPメソッド・クラスのパス・メソッドのパスの組み合わせごとにエンドポイントを作成
+        for http_method in &http_methods {
+            for base in &base_paths {
+                for path in &paths {
+                    let full_path = url_path::join(base, path);
+                    endpoints.push(Endpoint {
+                        class_name: class_name.to_string(),
+                        package: None,
+                        qualified_class_name: class_name.to_string(),
+                        method_name: method.name.clone(),
+                        http_method: http_method.to_string(),
+                        canonical_path: url_path::canonicalize(&full_path),
+                        path: full_path,
+                        parameters: parameters.clone(),
+                        line_range: method.line_range,
+                        file_path: declaration.scope.file_path.clone(),
+                        headers: headers.clone(),
+                        params: params.clone(),
+                        consumes: consumes.clone(),
+                        produces: produces.clone(),
+                        module: None,
+                    });
+                }
+            }
+        }
+    }
+
+    endpoints
+}
+
+// パス指定の引数を取得する関数
+// Springでは`path`は`value`のエイリアスなので、どちらで指定されていても対象とする
+fn path_argument(annotation: &Annotation) -> Option<&AnnotationValue> {
+    annotation.arg("value").or_else(|| annotation.arg("path"))
+}
+
+// アノテーション引数を、アノテーションが書かれているクラスのスコープで解決する
+// 文字列の定数参照は定数表を使って解決し、解決できない式は`<unresolved:式>`の形で残して
+// （Springのプレースホルダー`${...}`と区別するため）診断結果として報告する
+struct ArgumentResolver<'a> {
+    constants: &'a ConstantTable,
+    scope: ConstantScope,
+    class_name: &'a str,
+    file_path: &'a str,
+    // アノテーションが付与されたメソッドの開始行（クラスレベルの場合はNone）
+    line: Option<usize>,
+    diagnostics: &'a mut Vec<Diagnostic>,
+}
+
+impl<'a> ArgumentResolver<'a> {
+    fn new(
+        declaration: &'a TypeDeclaration,
+        constants: &'a ConstantTable,
+        line: Option<usize>,
+        diagnostics: &'a mut Vec<Diagnostic>,
+    ) -> Self {
+        ArgumentResolver {
+            constants,
+            scope: ConstantScope::for_class(&declaration.scope, &declaration.name),
+            class_name: &declaration.name,
+            file_path: &declaration.scope.file_path,
+            line,
+            diagnostics,
+        }
+    }
+
+    // 引数があれば解決する（解決できなかった値は除く）
+    fn argument(&mut self, annotation: &Annotation, key: &str) -> Option<Vec<String>> {
+        annotation.arg(key).map(|value| self.strings(value))
+    }
+
+    // 配列の場合は各要素を解決する（解決できなかった値は報告して除く）
+    fn strings(&mut self, value: &AnnotationValue) -> Vec<String> {
+        value
+            .elements()
+            .into_iter()
+            .filter_map(|element| match element {
+                AnnotationValue::Expr { text, expr } => self.string(text, expr.as_ref()),
+                AnnotationValue::Array(_) => None,
+            })
+            .collect()
+    }
+
+    // パスを解決する
+    // 解決できなかったパスは、エンドポイントが見えなくならないよう`<unresolved:式>`として残す
+    fn paths(&mut self, value: &AnnotationValue) -> Vec<String> {
+        value
+            .elements()
+            .into_iter()
+            .filter_map(|element| match element {
+                AnnotationValue::Expr { text, expr } => Some(
+                    self.string(text, expr.as_ref())
+                        .unwrap_or_else(|| format!("<unresolved:{}>", text)),
+                ),
+                AnnotationValue::Array(_) => None,
+            })
+            .collect()
+    }
+
+    // 式を文字列に解決する（解決できない場合は報告してNone）
+    fn string(&mut self, text: &str, expr: Option<&ConstantExpr>) -> Option<String> {
+        let message = match expr.map(|expr| self.constants.resolve(expr, &self.scope)) {
+            Some(Ok(value)) => return Some(value),
+            Some(Err(unresolved)) => format!("{} in {}", unresolved, self.class_name),
+            None => format!(
+                "Annotation argument {} in {} is not a constant string expression",
+                text, self.class_name
+            ),
+        };
+
+        self.warn(DiagnosticKind::UnresolvedConstant, message);
+        None
+    }
+
+    // RequestMappingのmethod属性からHTTPメソッドを抽出する
+    // 配列で複数指定されている場合はすべて返し、指定がない場合はANYとする
+    // RequestMethodの定数（`RequestMethod.GET`、static importした`GET`）以外は報告して無視する
+    fn request_mapping_methods(&mut self, annotation: &Annotation) -> Vec<String> {
+        let mut http_methods: Vec<String> = Vec::new();
+
+        for element in annotation
+            .arg("method")
+            .map(AnnotationValue::elements)
+            .unwrap_or_default()
+        {
+            let AnnotationValue::Expr { text, .. } = element else {
+                continue;
+            };
+            let Some(method_name) = request_method_constant(text) else {
+                let message = format!(
+                    "Request method {} in {} is not a RequestMethod constant",
+                    text, self.class_name
+                );
+                self.warn(DiagnosticKind::UnsupportedRequestMethod, message);
+                continue;
+            };
+            if !http_methods
+                .iter()
+                .any(|http_method| http_method == method_name)
+            {
+                http_methods.push(method_name.to_string());
+            }
+        }
+
+        if http_methods.is_empty() {
+            http_methods.push("ANY".to_string());
+        }
+
+        http_methods
+    }
+
+    fn warn(&mut self, kind: DiagnosticKind, message: String) {
+        let diagnostic =
+            Diagnostic::warning(kind, self.file_path, message).for_class(self.class_name);
+        self.diagnostics.push(match self.line {
+            Some(line) => diagnostic.at_line(line),
+            None => diagnostic,
+        });
+    }
+
+    // headers/params属性の条件を抽出する
+    fn conditions(&mut self, annotation: &Annotation, key: &str) -> Vec<Condition> {
+        self.argument(annotation, key)
+            .unwrap_or_default()
+            .iter()
+            .map(|expression| Condition::parse(expression))
+            .collect()
+    }
+}
+
+fn mapping_type_to_http_method(mapping_type: &str) -> String {
+    match mapping_type {
+        "GetMapping" => "GET".to_string(),
+        "PostMapping" => "POST".to_string(),
+        "PutMapping" => "PUT".to_string(),
+        "DeleteMapping" => "DELETE".to_string(),
+        "PatchMapping" => "PATCH".to_string(),
+        _ => "ANY".to_string(),
+    }
+}
+
+// `RequestMethod.GET`・`GET`（static import）・`org.springframework.web.bind.annotation.RequestMethod.GET`
+// の形の式からHTTPメソッド名を取得する関数（RequestMethodの定数でなければNone）
+fn request_method_constant(text: &str) -> Option<&str> {
+    let text = text.trim();
+    let (qualifier, name) = match text.rsplit_once('.') {
+        Some((qualifier, name)) => (Some(qualifier.trim()), name.trim()),
+        None => (None, text),
+    };
+    let is_request_method = qualifier.is_none_or(|qualifier| {
+        qualifier == "RequestMethod" || qualifier.ends_with(".RequestMethod")
+    });
+
+    (is_request_method && REQUEST_METHODS.contains(&name)).then_some(name)
+}
+
+// @PathVariable/@RequestBody/@RequestParamが付与された引数をパラメータとして抽出する関数
+fn parameters(method: &MethodDeclaration) -> Vec<Parameter> {
+    method
+        .parameters
+        .iter()
+        .filter_map(|parameter| {
+            let annotation = parameter
+                .annotations
+                .iter()
+                .find(|annotation| PARAMETER_ANNOTATIONS.contains(&annotation.name.as_str()))?;
+            Some(Parameter {
+                name: parameter.name.clone(),
+                param_type: parameter.param_type.clone(),
+                annotation: annotation.name.clone(),
+            })
+        })
+        .collect()
+}
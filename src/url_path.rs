@@ -0,0 +1,60 @@
+/// クラスのパスとメソッドのパスを結合する
+///
+/// Springと同様に、結合部分のスラッシュは1つにそろえ、先頭にはスラッシュを付与する。
+/// どちらも空の場合はルート（`/`）とする。
+/// 末尾のスラッシュは宣言されたまま残す（比較には`canonicalize`を使う）。
+pub fn join(base: &str, path: &str) -> String {
+    let base = base.trim();
+    let path = path.trim();
+
+    if base.is_empty() && path.is_empty() {
+        return "/".to_string();
+    }
+    if base.is_empty() {
+        return normalize(path);
+    }
+    if path.is_empty() {
+        return normalize(base);
+    }
+
+    normalize(&format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    ))
+}
+
+/// パスの表記ゆれを取り除く（連続したスラッシュをまとめ、先頭にスラッシュを付与する）
+pub fn normalize(path: &str) -> String {
+    let path = path.trim();
+    if path.is_empty() {
+        return String::new();
+    }
+
+    let mut normalized = String::with_capacity(path.len() + 1);
+    if !path.starts_with('/') {
+        normalized.push('/');
+    }
+    for c in path.chars() {
+        if c == '/' && normalized.ends_with('/') {
+            continue;
+        }
+        normalized.push(c);
+    }
+
+    normalized
+}
+
+/// 重複排除や比較に使う正規形を返す
+///
+/// `normalize`に加えて末尾のスラッシュを取り除き、空のパスはルート（`/`）として扱う。
+pub fn canonicalize(path: &str) -> String {
+    let normalized = normalize(path);
+    let trimmed = normalized.trim_end_matches('/');
+
+    if trimmed.is_empty() {
+        "/".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
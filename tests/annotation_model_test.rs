@@ -0,0 +1,197 @@
+use path_finder::annotation::{Annotation, AnnotationValue};
+use path_finder::class_index::Import;
+use path_finder::constants::ConstantExpr;
+use path_finder::frontend::{FrontendRegistry, TypeDeclaration};
+use path_finder::{scan_directory, Condition, Endpoint};
+use std::fs;
+use std::path::Path;
+
+const RESOURCES: &str = "tests/resources_annotation_model";
+
+// (HTTPメソッド, パス, headers, params, パラメータ) の一覧（クラス名は比較しない）
+type Summary = Vec<(
+    String,
+    String,
+    Vec<Condition>,
+    Vec<Condition>,
+    Vec<(String, String)>,
+)>;
+
+fn summary(endpoints: &[Endpoint], class_name: &str) -> Summary {
+    endpoints
+        .iter()
+        .filter(|endpoint| endpoint.class_name == class_name)
+        .map(|endpoint| {
+            (
+                endpoint.http_method.clone(),
+                endpoint.path.replace("invoices", "orders"),
+                endpoint.headers.clone(),
+                endpoint.params.clone(),
+                endpoint
+                    .parameters
+                    .iter()
+                    .map(|parameter| (parameter.name.clone(), parameter.annotation.clone()))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn types(file_name: &str) -> Vec<TypeDeclaration> {
+    let file_path = format!("{}/{}", RESOURCES, file_name);
+    let source_code = fs::read_to_string(&file_path).unwrap();
+    FrontendRegistry::default()
+        .for_path(Path::new(&file_path))
+        .unwrap()
+        .extract_types(&file_path, &source_code)
+        .unwrap()
+}
+
+fn literal(value: &str) -> AnnotationValue {
+    AnnotationValue::Expr {
+        text: format!("\"{}\"", value),
+        expr: Some(ConstantExpr::Literal(value.to_string())),
+    }
+}
+
+#[test]
+fn test_java_and_kotlin_build_the_same_annotations() {
+    let java = types("OrderController.java");
+    let kotlin = types("InvoiceController.kt");
+
+    let java_search = &java[0].methods[0].annotations[0];
+    let kotlin_search = &kotlin[0].methods[0].annotations[0];
+    assert_eq!(java_search.name, "GetMapping");
+    assert_eq!(
+        java_search.qualified_name.as_deref(),
+        None,
+        "ワイルドカードのimportからは完全修飾名を決めない"
+    );
+
+    // Javaの{...}、Kotlinのvarargの名前なし引数・arrayOf(...)はいずれも配列になる
+    let paths = AnnotationValue::Array(vec![literal("/search"), literal("/find")]);
+    assert_eq!(java_search.arg("value"), Some(&paths));
+    assert_eq!(kotlin_search.arg("value"), Some(&paths));
+    assert_eq!(java_search.arg("params"), kotlin_search.arg("params"));
+
+    // パッケージ修飾して書かれたアノテーション
+    let kotlin_cancel = &kotlin[0].methods[1].annotations[0];
+    assert_eq!(kotlin_cancel.name, "DeleteMapping");
+    assert_eq!(
+        kotlin_cancel.qualified_name.as_deref(),
+        Some("org.springframework.web.bind.annotation.DeleteMapping")
+    );
+    assert_eq!(java[0].methods[1].annotations[0], *kotlin_cancel);
+
+    // 引数のアノテーションも同じ形で読み取る
+    let java_parameter = &java[0].methods[0].parameters[0];
+    let kotlin_parameter = &kotlin[0].methods[0].parameters[0];
+    assert_eq!(java_parameter.name, kotlin_parameter.name);
+    assert_eq!(java_parameter.annotations, kotlin_parameter.annotations);
+    assert_eq!(kotlin[0].methods[0].parameters[1].param_type, "Int?");
+}
+
+#[test]
+fn test_java_and_kotlin_mappings_are_interpreted_the_same_way() {
+    let endpoints = scan_directory(RESOURCES).unwrap();
+
+    let java = summary(&endpoints, "OrderController");
+    let kotlin = summary(&endpoints, "InvoiceController");
+    assert_eq!(java.len(), 3, "{:?}", java);
+    assert_eq!(java, kotlin);
+
+    let (http_method, path, headers, params, parameters) = &java[0];
+    assert_eq!(http_method, "GET");
+    assert_eq!(path, "/orders/search");
+    assert_eq!(headers, &vec![Condition::parse("X-Api-Version=2")]);
+    assert_eq!(
+        params,
+        &vec![Condition::parse("q"), Condition::parse("status!=closed")]
+    );
+    // 引数付きの@RequestParam("q")もパラメータとして扱う
+    assert_eq!(
+        parameters,
+        &vec![
+            ("query".to_string(), "RequestParam".to_string()),
+            ("limit".to_string(), "RequestParam".to_string()),
+        ]
+    );
+
+    let (http_method, path, headers, _, parameters) = &java[2];
+    assert_eq!(http_method, "DELETE");
+    assert_eq!(path, "/orders/{id}");
+    assert_eq!(
+        headers,
+        &vec![
+            Condition::parse("X-Api-Version=2"),
+            Condition::parse("X-Confirm")
+        ]
+    );
+    assert_eq!(
+        parameters,
+        &vec![("id".to_string(), "PathVariable".to_string())]
+    );
+}
+
+#[test]
+fn test_qualified_name_is_resolved_from_imports() {
+    let imports = vec![
+        Import {
+            path: "org.springframework.web.bind.annotation.GetMapping".to_string(),
+            wildcard: false,
+            alias: None,
+        },
+        Import {
+            path: "com.example.web.ApiPost".to_string(),
+            wildcard: false,
+            alias: Some("Post".to_string()),
+        },
+    ];
+
+    let get = Annotation::new("GetMapping", &imports);
+    assert_eq!(get.name, "GetMapping");
+    assert_eq!(
+        get.qualified_name.as_deref(),
+        Some("org.springframework.web.bind.annotation.GetMapping")
+    );
+
+    let post = Annotation::new("Post", &imports);
+    assert_eq!(
+        post.qualified_name.as_deref(),
+        Some("com.example.web.ApiPost")
+    );
+
+    let unknown = Annotation::new("Transactional", &imports);
+    assert_eq!(unknown.qualified_name, None);
+}
+
+#[test]
+fn test_non_spring_annotations_with_spring_names_are_ignored() {
+    let endpoints = scan_directory(RESOURCES).unwrap();
+
+    // import宣言・パッケージ修飾から別のパッケージと分かるアノテーションはマッピングとして扱わない
+    let acme: Vec<(&str, &str)> = endpoints
+        .iter()
+        .filter(|endpoint| endpoint.class_name == "AcmeController")
+        .map(|endpoint| (endpoint.http_method.as_str(), endpoint.path.as_str()))
+        .collect();
+    assert_eq!(acme, vec![("PUT", "/acme/spring")]);
+
+    // Spring以外の@RequestMappingだけを持つクラスはコントローラーではない
+    assert!(!endpoints
+        .iter()
+        .any(|endpoint| endpoint.class_name == "AcmeClient"));
+}
+
+#[test]
+fn test_spring_annotations_imported_under_alias_are_mappings() {
+    let endpoints = scan_directory(RESOURCES).unwrap();
+
+    // `import ... as Get`の別名は、完全修飾名からSpringのアノテーションとして扱う
+    let aliased: Vec<(&str, &str)> = endpoints
+        .iter()
+        .filter(|endpoint| endpoint.class_name == "AliasedController")
+        .map(|endpoint| (endpoint.http_method.as_str(), endpoint.path.as_str()))
+        .collect();
+    assert_eq!(aliased, vec![("GET", "/aliased/alias")]);
+}
@@ -0,0 +1,112 @@
+use path_finder::class_index::{ClassEntry, ClassIndex, Resolution, TypeScope};
+use path_finder::{Supertype, SupertypeKind};
+
+fn supertype(name: &str, kind: SupertypeKind) -> Supertype {
+    Supertype {
+        name: name.to_string(),
+        kind,
+    }
+}
+
+// 完全修飾名でクラスを探す
+fn find<'a>(index: &'a ClassIndex, qualified_name: &str) -> Option<&'a ClassEntry> {
+    match index.resolve(qualified_name, &TypeScope::default()) {
+        Resolution::Found(entry) => Some(entry),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_class_index_entries() {
+    let index = ClassIndex::from_directory("tests/resources_class_index", &mut Vec::new());
+
+    // ネストしたクラスは外側のクラス名で修飾した完全修飾名で登録する
+    let read_handler = find(&index, "com.example.index.Handlers.ReadHandler")
+        .expect("ReadHandlerが登録されていません");
+    assert_eq!(read_handler.simple_name(), "ReadHandler");
+    assert!(read_handler.file_path.ends_with("Handlers.java"));
+    assert_eq!(
+        read_handler.supertypes,
+        vec![
+            supertype("AbstractHandler", SupertypeKind::Class),
+            supertype("Serializable", SupertypeKind::Interface),
+        ]
+    );
+
+    let auditing =
+        find(&index, "com.example.index.Handlers.Auditing").expect("Auditingが登録されていません");
+    assert_eq!(
+        auditing.supertypes,
+        vec![supertype(
+            "com.example.index.Versioned",
+            SupertypeKind::Interface
+        )]
+    );
+
+    // Kotlinのクラス・インターフェース（ファイル名と異なるクラスを含む）
+    let kotlin_handler =
+        find(&index, "com.example.index.KotlinHandler").expect("KotlinHandlerが登録されていません");
+    assert!(kotlin_handler.file_path.ends_with("Versioned.kt"));
+    assert_eq!(
+        kotlin_handler.supertypes,
+        vec![
+            supertype("AbstractHandler", SupertypeKind::Class),
+            supertype("Versioned", SupertypeKind::Interface),
+        ]
+    );
+    assert!(find(&index, "com.example.index.Versioned").is_some());
+    assert!(find(&index, "com.example.index.AbstractHandler").is_some());
+    assert!(find(&index, "com.example.index.Handlers").is_some());
+    assert!(find(&index, "com.example.index.Missing").is_none());
+}
+
+#[test]
+fn test_class_index_resolves_simple_name() {
+    let index = ClassIndex::from_directory("tests/resources_class_index", &mut Vec::new());
+
+    // 同じパッケージのクラスはクラス名だけで解決する
+    let scope = TypeScope {
+        package: Some("com.example.index".to_string()),
+        ..TypeScope::default()
+    };
+    match index.resolve("AbstractHandler", &scope) {
+        Resolution::Found(entry) => {
+            assert_eq!(entry.qualified_name(), "com.example.index.AbstractHandler")
+        }
+        other => panic!("AbstractHandlerが解決されていません: {:?}", other),
+    }
+    assert!(matches!(
+        index.resolve("Missing", &scope),
+        Resolution::NotFound
+    ));
+
+    // 継承・実装されているクラスの完全修飾名
+    let extended = index.extended_class_names();
+    assert!(extended.contains("com.example.index.AbstractHandler"));
+    assert!(extended.contains("com.example.index.Versioned"));
+    assert!(!extended.contains("com.example.index.Handlers"));
+}
+
+#[test]
+fn test_class_index_locates_declaration_node() {
+    let index = ClassIndex::from_directory("tests/resources_class_index", &mut Vec::new());
+    let read_handler = find(&index, "com.example.index.Handlers.ReadHandler").unwrap();
+
+    let source_code = std::fs::read_to_string(&read_handler.file_path).unwrap();
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_java::LANGUAGE.into())
+        .unwrap();
+    let tree = parser.parse(&source_code, None).unwrap();
+
+    // 記録したバイト範囲から、再度パースした構文木の宣言ノードを特定できる
+    let (start, end) = read_handler.byte_range;
+    let class_node = tree
+        .root_node()
+        .descendant_for_byte_range(start, end)
+        .filter(|node| node.start_byte() == start && node.end_byte() == end)
+        .expect("宣言ノードが見つかりません");
+    assert_eq!(class_node.kind(), "class_declaration");
+    let name_node = class_node.child_by_field_name("name").unwrap();
+    assert_eq!(&source_code[name_node.byte_range()], "ReadHandler");
+}
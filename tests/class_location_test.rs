@@ -0,0 +1,67 @@
+mod common;
+
+use common::find_by_path;
+use path_finder::class_index::{ClassIndex, Resolution, TypeScope};
+use path_finder::scan_directory;
+
+#[test]
+fn test_parent_in_file_not_named_after_it() {
+    let endpoints = scan_directory("tests/resources_class_locations").unwrap();
+
+    // 1つのファイルに複数のクラスを宣言しているKotlinファイル
+    let kotlin_base = find_by_path(&endpoints, "/kotlin-multi/kotlin-base")
+        .expect("Controllers.ktの親クラスが見つかりません");
+    assert!(kotlin_base.file_path.ends_with("Controllers.kt"));
+    let kotlin_api = find_by_path(&endpoints, "/kotlin-multi/kotlin-api")
+        .expect("Controllers.ktのインターフェースが見つかりません");
+    assert_eq!(kotlin_api.class_name, "KotlinLocationController");
+
+    // 別のファイルに宣言されたパッケージプライベートなクラス
+    let hidden = find_by_path(&endpoints, "/hidden-parent/hidden")
+        .expect("Support.javaの親クラスが見つかりません");
+    assert_eq!(hidden.class_name, "HiddenBase");
+    assert!(hidden.file_path.ends_with("Support.java"));
+}
+
+#[test]
+fn test_nested_parent_classes() {
+    let endpoints = scan_directory("tests/resources_class_locations").unwrap();
+
+    // `extends Support.Inner`（Java）、`: Support.Inner()`（Kotlin、importしたOuterから解決）
+    let java_inner = find_by_path(&endpoints, "/inner-java/inner")
+        .expect("Support.Innerが解決されていません（Java）");
+    assert_eq!(java_inner.class_name, "Inner");
+    assert!(find_by_path(&endpoints, "/inner-kotlin/inner").is_some());
+
+    // 外側のクラスのメンバーは同じパッケージのトップレベルクラスより優先される
+    let sibling =
+        find_by_path(&endpoints, "/holder/sibling").expect("Holder.Baseが解決されていません");
+    assert!(sibling.file_path.ends_with("Holder.java"));
+    assert!(find_by_path(&endpoints, "/holder/top-level-base").is_none());
+    // 同じパッケージのBaseはどのクラスにも継承されていないため、単独のコントローラーとして扱う
+    let top_level = find_by_path(&endpoints, "/top-level-base")
+        .expect("継承されていないBaseがコントローラーとして扱われていません");
+    assert!(top_level.file_path.ends_with("Base.java"));
+
+    // 外側のクラスのエンドポイントとして、ネストしたクラスのメソッドを出力しない
+    assert!(!endpoints
+        .iter()
+        .any(|e| e.class_name == "Holder" || e.class_name == "Support"));
+    assert_eq!(endpoints.len(), 7);
+}
+
+#[test]
+fn test_nested_class_addressable_as_outer_inner() {
+    let index = ClassIndex::from_directory("tests/resources_class_locations", &mut Vec::new());
+
+    let scope = TypeScope {
+        package: Some("com.example.locations".to_string()),
+        ..TypeScope::default()
+    };
+    for name in ["Support.Inner", "com.example.locations.Support.Inner"] {
+        match index.resolve(name, &scope) {
+            Resolution::Found(entry) => assert_eq!(entry.name, "Support.Inner"),
+            other => panic!("{}が解決されていません: {:?}", name, other),
+        }
+    }
+}
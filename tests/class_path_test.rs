@@ -1,19 +1,27 @@
-use path_finder::java;
+use path_finder::class_index::TypeScope;
+use path_finder::diagnostics::{DiagnosticKind, Severity};
+use path_finder::missing_parent::{MissingParentFilter, MissingParentPolicy};
+use path_finder::Endpoint;
+
+// 1つのファイルからエンドポイントを抽出する（言語は拡張子から判定する）
+fn extract_endpoints(file_path: &str, scan_root_dir: &str) -> anyhow::Result<Vec<Endpoint>> {
+    path_finder::extract_file_report(file_path, scan_root_dir).map(|report| report.endpoints)
+}
 
 #[test]
 fn test_missing_parent_class_warnings() {
-    // 標準エラー出力キャプチャ用構造体は現在未使用のため削除
-    // 将来的に警告キャプチャが必要になった場合に再実装
-
     // Spring標準クラスを継承するケース（BaseEntityが存在しない）
-    let result = java::extract_request_mapping_with_inheritance(
+    let report = path_finder::extract_file_report(
         "tests/resources_class_path/SpringStandardParentController.java",
         "tests/resources_class_path",
-    );
+    )
+    .unwrap();
+
+    // Spring標準クラスが見つからなくても警告は出ない
+    assert!(report.diagnostics.is_empty());
 
-    assert!(result.is_ok());
-    let endpoints = result.unwrap();
     // エンドポイント自体は正常に抽出される
+    let endpoints = report.endpoints;
     assert_eq!(endpoints.len(), 2);
     assert_eq!(endpoints[0].http_method, "GET");
     assert_eq!(endpoints[0].path, "/api/spring/standard");
@@ -24,7 +32,7 @@ fn test_missing_parent_class_warnings() {
 #[test]
 fn test_external_library_parent_class() {
     // 外部ライブラリクラスを継承するケース（JpaRepositoryが存在しない）
-    let result = java::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/ExternalLibraryController.java",
         "tests/resources_class_path",
     );
@@ -41,7 +49,7 @@ fn test_external_library_parent_class() {
 #[test]
 fn test_missing_custom_parent_class() {
     // 存在しないカスタムクラスを継承するケース
-    let result = java::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/MissingParentController.java",
         "tests/resources_class_path",
     );
@@ -53,12 +61,36 @@ fn test_missing_custom_parent_class() {
     assert_eq!(endpoints[0].path, "/api/missing/test");
     assert_eq!(endpoints[1].http_method, "PUT");
     assert_eq!(endpoints[1].path, "/api/missing/update");
+
+    // 見つからなかった親クラスは警告として返される
+    let report = path_finder::extract_file_report(
+        "tests/resources_class_path/MissingParentController.java",
+        "tests/resources_class_path",
+    )
+    .unwrap();
+    assert_eq!(report.endpoints.len(), 2);
+    assert_eq!(report.diagnostics.len(), 1);
+    let warning = &report.diagnostics[0];
+    assert_eq!(warning.severity, Severity::Warning);
+    assert_eq!(warning.kind, DiagnosticKind::UnresolvedParent);
+    assert_eq!(
+        warning.class_name.as_deref(),
+        Some("MissingParentController")
+    );
+    assert_eq!(
+        warning.file_path,
+        "tests/resources_class_path/MissingParentController.java"
+    );
+    assert_eq!(
+        warning.message,
+        "Parent class NonExistentParentClass not found for MissingParentController"
+    );
 }
 
 #[test]
 fn test_wrong_class_name_file() {
     // ファイル名とクラス名が異なるケース
-    let result = java::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/WrongClassNameFile.java",
         "tests/resources_class_path",
     );
@@ -66,12 +98,17 @@ fn test_wrong_class_name_file() {
     assert!(result.is_ok());
     let endpoints = result.unwrap();
 
-    // 親クラスSomeParentClassは存在するが、ファイル名検索では見つからない可能性がある
-    // この場合でも子クラスのエンドポイントは抽出される
-    assert!(endpoints.len() >= 1);
+    // ファイル名とクラス名が異なっていても、子クラス・親クラスのエンドポイントが抽出される
+    assert_eq!(endpoints.len(), 3);
     assert_eq!(endpoints[0].http_method, "GET");
     assert_eq!(endpoints[0].path, "/api/wrong/actual");
     assert_eq!(endpoints[0].class_name, "ActualClassName");
+    assert!(endpoints
+        .iter()
+        .any(|e| e.path == "/api/wrong/method" && e.class_name == "SomeParentClass"));
+    assert!(endpoints
+        .iter()
+        .any(|e| e.path == "/api/wrong/create" && e.class_name == "SomeParentClass"));
 }
 
 #[test]
@@ -109,12 +146,28 @@ fn test_scan_directory_with_warnings() {
         .filter(|e| e.path.starts_with("/api/wrong"))
         .collect();
     assert_eq!(wrong_endpoints.len(), 3); // 子クラス1個 + 継承されたメソッド2個
+
+    // 警告はエンドポイントと一緒に返され、カスタムクラスの親クラスだけが報告される
+    let report = path_finder::scan_directory_report("tests/resources_class_path").unwrap();
+    assert_eq!(report.endpoints.len(), endpoints.len());
+    assert!(!report.has_errors());
+    let warned_classes: Vec<_> = report
+        .warnings()
+        .map(|warning| (warning.kind, warning.class_name.as_deref()))
+        .collect();
+    assert_eq!(
+        warned_classes,
+        vec![(
+            DiagnosticKind::UnresolvedParent,
+            Some("MissingParentController")
+        )]
+    );
 }
 
 #[test]
 fn test_valid_parent_class_inheritance() {
     // 正しく親クラスが見つかるケース（パス結合のテスト）
-    let result = java::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/ValidParentController.java",
         "tests/resources_class_path",
     );
@@ -142,7 +195,7 @@ fn test_valid_parent_class_inheritance() {
         .filter(|e| e.class_name == "SomeParentClass")
         .collect();
 
-    if parent_endpoints.len() > 0 {
+    if !parent_endpoints.is_empty() {
         // 親クラスのパスが子クラスのbase_pathと結合されていることを確認
         // 期待値: /api/child + /api/parent/method = /api/child/api/parent/method
         // または適切なパス結合ロジックによる結果
@@ -159,7 +212,7 @@ fn test_valid_parent_class_inheritance() {
 #[test]
 fn test_path_combination_logic() {
     // パス結合ロジックの詳細テスト
-    let result = java::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/ValidParentController.java",
         "tests/resources_class_path",
     );
@@ -189,7 +242,7 @@ fn test_path_combination_logic() {
 #[test]
 fn test_kotlin_child_java_parent() {
     // Kotlin子クラス → Java親クラスの継承パターン
-    let result = path_finder::kotlin::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/KotlinChildController.kt",
         "tests/resources_class_path",
     );
@@ -223,7 +276,7 @@ fn test_kotlin_child_java_parent() {
         .filter(|e| e.class_name == "JavaParentClass")
         .collect();
 
-    if parent_endpoints.len() > 0 {
+    if !parent_endpoints.is_empty() {
         println!("✅ Cross-language inheritance works: Kotlin -> Java");
     } else {
         println!("❌ Cross-language inheritance failed: Kotlin cannot find Java parent");
@@ -233,7 +286,7 @@ fn test_kotlin_child_java_parent() {
 #[test]
 fn test_java_child_kotlin_parent() {
     // Java子クラス → Kotlin親クラスの継承パターン
-    let result = path_finder::java::extract_request_mapping_with_inheritance(
+    let result = extract_endpoints(
         "tests/resources_class_path/JavaChildController.java",
         "tests/resources_class_path",
     );
@@ -267,7 +320,7 @@ fn test_java_child_kotlin_parent() {
         .filter(|e| e.class_name == "KotlinParentClass")
         .collect();
 
-    if parent_endpoints.len() > 0 {
+    if !parent_endpoints.is_empty() {
         println!("✅ Cross-language inheritance works: Java -> Kotlin");
     } else {
         println!("❌ Cross-language inheritance failed: Java cannot find Kotlin parent");
@@ -280,7 +333,7 @@ fn test_comprehensive_cross_language_inheritance() {
     println!("=== Comprehensive Cross-Language Inheritance Test ===");
 
     // 1. Kotlin → Java 継承
-    let kotlin_to_java = path_finder::kotlin::extract_request_mapping_with_inheritance(
+    let kotlin_to_java = extract_endpoints(
         "tests/resources_class_path/KotlinChildController.kt",
         "tests/resources_class_path",
     )
@@ -317,7 +370,7 @@ fn test_comprehensive_cross_language_inheritance() {
         .any(|e| e.path.contains("java-create")));
 
     // 2. Java → Kotlin 継承
-    let java_to_kotlin = path_finder::java::extract_request_mapping_with_inheritance(
+    let java_to_kotlin = extract_endpoints(
         "tests/resources_class_path/JavaChildController.java",
         "tests/resources_class_path",
     )
@@ -361,7 +414,7 @@ fn test_cross_language_path_combinations() {
     // クロス言語継承でのパス結合ロジックテスト
 
     // Kotlin child (/api/kotlin-child) + Java parent methods
-    let kotlin_to_java = path_finder::kotlin::extract_request_mapping_with_inheritance(
+    let kotlin_to_java = extract_endpoints(
         "tests/resources_class_path/KotlinChildController.kt",
         "tests/resources_class_path",
     )
@@ -379,7 +432,7 @@ fn test_cross_language_path_combinations() {
     }
 
     // Java child (/api/java-child) + Kotlin parent methods
-    let java_to_kotlin = path_finder::java::extract_request_mapping_with_inheritance(
+    let java_to_kotlin = extract_endpoints(
         "tests/resources_class_path/JavaChildController.java",
         "tests/resources_class_path",
     )
@@ -399,8 +452,13 @@ fn test_cross_language_path_combinations() {
 
 #[test]
 fn test_excluded_parent_classes() {
-    // 将来的に除外リストが実装された時のテスト
-    // 現在は警告が出るが、将来的には警告が出ないことを確認する
+    // 組み込みの除外リストにある親クラスは、見つからなくても警告しない
+    let filter = MissingParentFilter::new(&MissingParentPolicy::default()).unwrap();
+    let scope = TypeScope {
+        file_path: "tests/resources_class_path/SpringStandardParentController.java".to_string(),
+        package: Some("com.example.controller".to_string()),
+        ..TypeScope::default()
+    };
 
     let spring_standard_parents = [
         "BaseEntity",
@@ -411,10 +469,21 @@ fn test_excluded_parent_classes() {
         "CrudRepository",
         "Repository",
     ];
-
     for parent_class in &spring_standard_parents {
-        // この段階では具体的な除外ロジックはないので、
-        // 警告が出ることを確認するテストとして残す
-        println!("Testing parent class: {}", parent_class);
+        assert!(
+            !filter.should_warn(parent_class, &scope),
+            "{}が警告されます",
+            parent_class
+        );
     }
+    assert!(filter.should_warn("NonExistentParentClass", &scope));
+
+    // 除外リストにあるクラス名も、設定で警告するように変更できる
+    let filter = MissingParentFilter::new(&MissingParentPolicy {
+        warn: vec!["JpaRepository".to_string()],
+        ..MissingParentPolicy::default()
+    })
+    .unwrap();
+    assert!(filter.should_warn("JpaRepository", &scope));
+    assert!(!filter.should_warn("CrudRepository", &scope));
 }
@@ -0,0 +1,39 @@
+// 結合テストで共通して使う補助関数
+// （テストファイルごとに一部の関数しか使わないため、未使用の警告は出さない）
+#![allow(dead_code)]
+
+use path_finder::{Condition, ConditionOperator, Endpoint};
+
+/// クラス名とメソッド名でエンドポイントを探す
+pub fn find<'a>(
+    endpoints: &'a [Endpoint],
+    class_name: &str,
+    method_name: &str,
+) -> Option<&'a Endpoint> {
+    endpoints
+        .iter()
+        .find(|e| e.class_name == class_name && e.method_name == method_name)
+}
+
+/// パスでエンドポイントを探す
+pub fn find_by_path<'a>(endpoints: &'a [Endpoint], path: &str) -> Option<&'a Endpoint> {
+    endpoints.iter().find(|e| e.path == path)
+}
+
+/// クラス名とメソッド名が一致するエンドポイントのパスを出力順で返す
+pub fn paths_of(endpoints: &[Endpoint], class_name: &str, method_name: &str) -> Vec<String> {
+    endpoints
+        .iter()
+        .filter(|e| e.class_name == class_name && e.method_name == method_name)
+        .map(|e| e.path.clone())
+        .collect()
+}
+
+/// 期待値用の条件を組み立てる
+pub fn condition(name: &str, operator: ConditionOperator, value: Option<&str>) -> Condition {
+    Condition {
+        name: name.to_string(),
+        operator,
+        value: value.map(|value| value.to_string()),
+    }
+}
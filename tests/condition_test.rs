@@ -0,0 +1,114 @@
+mod common;
+
+use common::{condition, find};
+use path_finder::diagnostics::DiagnosticKind;
+use path_finder::{scan_directory, scan_directory_report, Condition, ConditionOperator};
+
+#[test]
+fn test_parse_condition_expressions() {
+    assert_eq!(
+        Condition::parse("version=1"),
+        condition("version", ConditionOperator::Equals, Some("1"))
+    );
+    assert_eq!(
+        Condition::parse("mode != legacy"),
+        condition("mode", ConditionOperator::NotEquals, Some("legacy"))
+    );
+    assert_eq!(
+        Condition::parse("X-Request-Id"),
+        condition("X-Request-Id", ConditionOperator::Present, None)
+    );
+    assert_eq!(
+        Condition::parse("!debug"),
+        condition("debug", ConditionOperator::Absent, None)
+    );
+}
+
+#[test]
+fn test_java_conditions() {
+    let endpoints = scan_directory("tests/resources_conditions").unwrap();
+
+    // クラスレベルのheadersとメソッドレベルのparams（定数参照を含む）
+    let list = find(&endpoints, "VersionedController", "list").unwrap();
+    assert_eq!(
+        list.headers,
+        vec![condition(
+            "X-API-Version",
+            ConditionOperator::Equals,
+            Some("2")
+        )]
+    );
+    assert_eq!(
+        list.params,
+        vec![
+            condition("mode", ConditionOperator::NotEquals, Some("legacy")),
+            condition("debug", ConditionOperator::Absent, None),
+        ]
+    );
+
+    // クラスレベルとメソッドレベルのheadersを組み合わせる
+    let create = find(&endpoints, "VersionedController", "create").unwrap();
+    assert_eq!(
+        create.headers,
+        vec![
+            condition("X-API-Version", ConditionOperator::Equals, Some("2")),
+            condition("X-Request-Id", ConditionOperator::Present, None),
+            condition(
+                "Content-Encoding",
+                ConditionOperator::NotEquals,
+                Some("gzip")
+            ),
+        ]
+    );
+    assert!(create.params.is_empty());
+}
+
+#[test]
+fn test_kotlin_conditions() {
+    let endpoints = scan_directory("tests/resources_conditions").unwrap();
+
+    let list_features = find(&endpoints, "FeatureController", "listFeatures").unwrap();
+    assert_eq!(
+        list_features.headers,
+        vec![condition("X-Tenant", ConditionOperator::Present, None)]
+    );
+    assert_eq!(
+        list_features.params,
+        vec![
+            condition("preview", ConditionOperator::Absent, None),
+            condition("flag", ConditionOperator::Equals, Some("on")),
+        ]
+    );
+}
+
+#[test]
+fn test_unresolved_conditions_are_skipped_and_reported() {
+    let report = scan_directory_report("tests/resources_conditions").unwrap();
+
+    // 解決できなかった定数を条件名・メディアタイプとして扱わない
+    let missing = find(
+        &report.endpoints,
+        "UnresolvedConditionController",
+        "missing",
+    )
+    .expect("missingメソッドが見つかりません");
+    assert_eq!(
+        missing.headers,
+        vec![condition("X-Known", ConditionOperator::Present, None)]
+    );
+    assert!(missing.produces.is_empty());
+
+    let unresolved: Vec<&str> = report
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.kind == DiagnosticKind::UnresolvedConstant)
+        .map(|diagnostic| diagnostic.message.as_str())
+        .collect();
+    assert_eq!(unresolved.len(), 2, "{:?}", unresolved);
+    assert!(unresolved
+        .iter()
+        .any(|message| message.contains("MissingHeaders.NAME")));
+    assert!(unresolved
+        .iter()
+        .any(|message| message.contains("MissingTypes.JSON")));
+}
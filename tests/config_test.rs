@@ -0,0 +1,173 @@
+use path_finder::config::{Config, OutputFormat, CONFIG_FILE_NAME};
+use path_finder::{scan_directories_report_with_options, ScanOptions};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const RESOURCES: &str = "tests/resources_config";
+
+// (HTTPメソッド, パス, クラス名#メソッド名) の一覧
+fn endpoint_summaries(roots: &[String], options: &ScanOptions) -> Vec<(String, String, String)> {
+    let report = scan_directories_report_with_options(roots, options).unwrap();
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+    report
+        .endpoints
+        .iter()
+        .map(|endpoint| {
+            (
+                endpoint.http_method.clone(),
+                endpoint.path.clone(),
+                format!("{}#{}", endpoint.class_name, endpoint.method_name),
+            )
+        })
+        .collect()
+}
+
+fn summary(http_method: &str, path: &str, method: &str) -> (String, String, String) {
+    (
+        http_method.to_string(),
+        path.to_string(),
+        method.to_string(),
+    )
+}
+
+#[test]
+fn test_discover_from_nested_directory() {
+    let (path, config) = Config::discover(Path::new("tests/resources_config/api/com/example"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        path,
+        Path::new(RESOURCES)
+            .join(CONFIG_FILE_NAME)
+            .canonicalize()
+            .unwrap()
+    );
+    assert_eq!(config.roots, vec!["api", "common"]);
+}
+
+#[test]
+fn test_load_all_settings() {
+    let config = Config::load(&Path::new(RESOURCES).join(CONFIG_FILE_NAME)).unwrap();
+
+    assert_eq!(
+        config.root_paths(Path::new(RESOURCES)),
+        vec![
+            "tests/resources_config/api",
+            "tests/resources_config/common"
+        ]
+    );
+    assert!(config.include.is_empty());
+    assert_eq!(config.exclude, vec!["generated"]);
+    assert_eq!(config.context_path.as_deref(), Some("/service"));
+    assert_eq!(config.format, Some(OutputFormat::Json));
+    assert_eq!(
+        config.mapping_annotations.get("ApiGet").map(String::as_str),
+        Some("GetMapping")
+    );
+    assert_eq!(config.missing_parents.suppress, vec!["*Support"]);
+}
+
+#[test]
+fn test_scan_with_config() {
+    let config = Config::load(&Path::new(RESOURCES).join(CONFIG_FILE_NAME)).unwrap();
+
+    // 別名のアノテーションをJava・Kotlinの両方で認識し、generatedディレクトリは除外する
+    // 親クラスはもう一方のルート（common）から見つける
+    assert_eq!(
+        endpoint_summaries(
+            &config.root_paths(Path::new(RESOURCES)),
+            &config.scan_options()
+        ),
+        vec![
+            summary("GET", "/service/orders/{id}", "OrderController#getOrder"),
+            summary("GET", "/service/users/{id}", "UserController#getUser"),
+            summary("POST", "/service/users", "UserController#createUser"),
+            summary("GET", "/service/users/health", "BaseApiController#health"),
+        ]
+    );
+}
+
+#[test]
+fn test_scan_without_aliases() {
+    // 別名がなければ独自のアノテーションのコントローラーは検出されない
+    let roots = vec![format!("{}/api", RESOURCES)];
+    let options = ScanOptions {
+        exclude: vec!["generated".to_string()],
+        ..ScanOptions::default()
+    };
+    let report = scan_directories_report_with_options(&roots, &options).unwrap();
+    assert!(report.endpoints.is_empty());
+}
+
+#[test]
+fn test_include_and_exclude_patterns() {
+    let roots = vec![RESOURCES.to_string()];
+
+    let options = ScanOptions {
+        include: vec!["scripts".to_string(), "api/generated/**".to_string()],
+        ..ScanOptions::default()
+    };
+    assert_eq!(
+        endpoint_summaries(&roots, &options),
+        vec![
+            summary("GET", "/generated", "GeneratedController#generated"),
+            summary("GET", "/script", "ScriptController#script"),
+        ]
+    );
+
+    let options = ScanOptions {
+        include: vec!["*.java".to_string()],
+        exclude: vec!["api".to_string(), "common".to_string()],
+        ..ScanOptions::default()
+    };
+    assert_eq!(
+        endpoint_summaries(&roots, &options),
+        vec![summary("GET", "/script", "ScriptController#script")]
+    );
+}
+
+#[test]
+fn test_context_path() {
+    let roots = vec![format!("{}/scripts", RESOURCES)];
+    for context_path in ["/app", "app/", "/app/"] {
+        let options = ScanOptions {
+            context_path: Some(context_path.to_string()),
+            ..ScanOptions::default()
+        };
+        assert_eq!(
+            endpoint_summaries(&roots, &options),
+            vec![summary("GET", "/app/script", "ScriptController#script")]
+        );
+    }
+}
+
+#[test]
+fn test_invalid_settings_are_reported() {
+    let roots = vec![RESOURCES.to_string()];
+
+    let options = ScanOptions {
+        mapping_annotations: BTreeMap::from([("ApiGet".to_string(), "Component".to_string())]),
+        ..ScanOptions::default()
+    };
+    let error = format!(
+        "{:#}",
+        scan_directories_report_with_options(&roots, &options).unwrap_err()
+    );
+    assert!(error.contains("ApiGet"), "{}", error);
+
+    let options = ScanOptions {
+        exclude: vec!["generated[".to_string()],
+        ..ScanOptions::default()
+    };
+    let error = format!(
+        "{:#}",
+        scan_directories_report_with_options(&roots, &options).unwrap_err()
+    );
+    assert!(error.contains("generated["), "{}", error);
+
+    let error = format!(
+        "{:#}",
+        toml::from_str::<Config>("format = \"yaml\"").unwrap_err()
+    );
+    assert!(error.contains("yaml"), "{}", error);
+}
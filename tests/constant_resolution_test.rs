@@ -0,0 +1,129 @@
+mod common;
+
+use common::find;
+use path_finder::diagnostics::{DiagnosticKind, Severity};
+use path_finder::{scan_directory, scan_directory_report};
+
+#[test]
+fn test_java_constant_paths() {
+    let endpoints = scan_directory("tests/resources_constants").unwrap();
+
+    // 他クラスの定数と文字列結合を含むクラスレベルのパス
+    let get_user =
+        find(&endpoints, "UserController", "getUser").expect("getUserメソッドが見つかりません");
+    assert_eq!(get_user.path, "/api/users/v2/{id}");
+
+    // 同じクラス内の定数
+    let search_users = find(&endpoints, "UserController", "searchUsers")
+        .expect("searchUsersメソッドが見つかりません");
+    assert_eq!(search_users.path, "/api/users/v2/search");
+
+    // ネストしたクラスの定数
+    let admin_update = find(&endpoints, "UserController", "adminUpdate")
+        .expect("adminUpdateメソッドが見つかりません");
+    assert_eq!(admin_update.http_method, "POST");
+    assert_eq!(admin_update.path, "/api/users/v2/admin/{id}");
+}
+
+#[test]
+fn test_unresolved_constant_is_kept_as_placeholder() {
+    let endpoints = scan_directory("tests/resources_constants").unwrap();
+
+    let delete_user = find(&endpoints, "UserController", "deleteUser")
+        .expect("deleteUserメソッドが見つかりません");
+    assert_eq!(
+        delete_user.path,
+        "/api/users/v2/<unresolved:UndefinedPaths.REMOVED>"
+    );
+}
+
+#[test]
+fn test_unresolved_constant_is_reported() {
+    let report = scan_directory_report("tests/resources_constants").unwrap();
+
+    let unresolved: Vec<_> = report
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.kind == DiagnosticKind::UnresolvedConstant)
+        .collect();
+    assert_eq!(unresolved.len(), 1, "{:?}", report.diagnostics);
+    assert_eq!(unresolved[0].severity, Severity::Warning);
+    assert_eq!(
+        unresolved[0].file_path,
+        "tests/resources_constants/UserController.java"
+    );
+    assert_eq!(unresolved[0].class_name.as_deref(), Some("UserController"));
+    assert_eq!(unresolved[0].line, Some(25));
+    assert!(unresolved[0].message.contains("UndefinedPaths.REMOVED"));
+}
+
+#[test]
+fn test_constants_are_resolved_through_package_and_imports() {
+    let endpoints = scan_directory("tests/resources_constant_scope").unwrap();
+    let path = |class_name, method_name| {
+        find(&endpoints, class_name, method_name)
+            .unwrap_or_else(|| panic!("{}.{}が見つかりません", class_name, method_name))
+            .path
+            .as_str()
+    };
+
+    // 修飾なしのクラス名は同じパッケージ → import宣言の順に解決する
+    assert_eq!(path("SecondController", "samePackage"), "/second");
+    assert_eq!(path("WebController", "imported"), "/second/imported");
+    // static import（Java）、別名を付けたimport（Kotlin）
+    assert_eq!(path("WebController", "staticImport"), "/first/static");
+    assert_eq!(path("KotlinController", "alias"), "/kotlin/alias");
+    // importしていない別パッケージのトップレベル定数は推測しない
+    assert_eq!(
+        path("KotlinController", "notImported"),
+        "/<unresolved:KOTLIN_ROOT>"
+    );
+}
+
+#[test]
+fn test_ambiguous_constant_is_reported() {
+    let report = scan_directory_report("tests/resources_constant_scope").unwrap();
+
+    let ambiguous = find(&report.endpoints, "WildcardController", "ambiguous")
+        .expect("ambiguousメソッドが見つかりません");
+    assert_eq!(ambiguous.path, "/<unresolved:Paths.ROOT>");
+
+    let diagnostic = report
+        .diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.class_name.as_deref() == Some("WildcardController"))
+        .expect("曖昧な定数参照が報告されていません");
+    assert_eq!(diagnostic.kind, DiagnosticKind::UnresolvedConstant);
+    assert!(diagnostic.message.contains("com.example.first.Paths.ROOT"));
+    assert!(diagnostic.message.contains("com.example.second.Paths.ROOT"));
+}
+
+#[test]
+fn test_escape_sequences_in_string_literals() {
+    let endpoints = scan_directory("tests/resources_constant_scope").unwrap();
+
+    let escaped =
+        find(&endpoints, "SecondController", "escaped").expect("escapedメソッドが見つかりません");
+    assert_eq!(escaped.path, "/say\"hi\"/café");
+}
+
+#[test]
+fn test_kotlin_constant_paths() {
+    let endpoints = scan_directory("tests/resources_constants").unwrap();
+
+    // objectの定数（文字列テンプレートでトップレベル定数を参照）
+    let get_order = find(&endpoints, "OrderController", "getOrder")
+        .expect("Kotlin: getOrderメソッドが見つかりません");
+    assert_eq!(get_order.path, "/api/orders/{id}");
+
+    // companion objectの定数（配列指定）
+    let add_item = find(&endpoints, "OrderController", "addItem")
+        .expect("Kotlin: addItemメソッドが見つかりません");
+    assert_eq!(add_item.http_method, "POST");
+    assert_eq!(add_item.path, "/api/orders/items");
+
+    // 文字列テンプレート内の定数参照
+    let get_item = find(&endpoints, "OrderController", "getItem")
+        .expect("Kotlin: getItemメソッドが見つかりません");
+    assert_eq!(get_item.path, "/api/orders/items/{itemId}");
+}
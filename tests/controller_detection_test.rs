@@ -0,0 +1,92 @@
+mod common;
+
+use common::find;
+use path_finder::{scan_directory, Endpoint};
+
+#[test]
+fn test_rest_controller_without_class_mapping() {
+    let endpoints = scan_directory("tests/resources_controller").unwrap();
+
+    let list_orders = find(&endpoints, "OrderController", "listOrders")
+        .expect("@RestControllerのみのクラスのエンドポイントが見つかりません");
+    assert_eq!(list_orders.http_method, "GET");
+    assert_eq!(list_orders.path, "/orders");
+
+    // メソッドレベルの@RequestMappingがクラスのベースパスとして扱われないこと
+    let delete_order = find(&endpoints, "OrderController", "deleteOrder")
+        .expect("deleteOrderメソッドが見つかりません");
+    assert_eq!(delete_order.http_method, "DELETE");
+    assert_eq!(delete_order.path, "/orders/{id}");
+}
+
+#[test]
+fn test_controller_without_class_mapping_kotlin() {
+    let endpoints = scan_directory("tests/resources_controller").unwrap();
+
+    let list_invoices = find(&endpoints, "InvoiceController", "listInvoices")
+        .expect("Kotlin: @Controllerのみのクラスのエンドポイントが見つかりません");
+    assert_eq!(list_invoices.http_method, "GET");
+    assert_eq!(list_invoices.path, "/invoices");
+
+    let create_invoice = find(&endpoints, "InvoiceController", "createInvoice")
+        .expect("Kotlin: createInvoiceメソッドが見つかりません");
+    assert_eq!(create_invoice.http_method, "POST");
+    assert_eq!(create_invoice.path, "/invoices");
+}
+
+#[test]
+fn test_class_with_method_mappings_only() {
+    let endpoints = scan_directory("tests/resources_controller").unwrap();
+
+    let ping = find(&endpoints, "PingHandler", "ping")
+        .expect("メソッドレベルのマッピングのみのクラスが検出されていません");
+    assert_eq!(ping.path, "/ping");
+
+    let health = find(&endpoints, "HealthHandler", "health")
+        .expect("Kotlin: メソッドレベルのマッピングのみのクラスが検出されていません");
+    assert_eq!(health.path, "/health");
+}
+
+#[test]
+fn test_extended_base_class_is_not_reported_standalone() {
+    let endpoints = scan_directory("tests/resources_controller").unwrap();
+
+    // 親クラスのメソッドは継承先のコントローラー経由で1回だけ出力される
+    let audit_endpoints: Vec<&Endpoint> = endpoints
+        .iter()
+        .filter(|e| e.class_name == "AuditedController")
+        .collect();
+    assert_eq!(
+        audit_endpoints.len(),
+        1,
+        "親クラスのエンドポイントが重複しています"
+    );
+    assert_eq!(audit_endpoints[0].path, "/audit");
+
+    assert_eq!(endpoints.len(), 7);
+}
+
+#[test]
+fn test_extended_class_is_matched_by_qualified_name() {
+    let endpoints = scan_directory("tests/resources_controller_packages").unwrap();
+
+    // com.example.x.BaseControllerは継承先のコントローラー経由でのみ出力される
+    let x_base: Vec<&Endpoint> = endpoints
+        .iter()
+        .filter(|e| e.method_name == "base")
+        .collect();
+    assert_eq!(x_base.len(), 1);
+    assert_eq!(x_base[0].path, "/x/x-base");
+    assert!(x_base[0].file_path.ends_with("x/BaseController.java"));
+
+    // 同名でも別パッケージのcom.example.y.BaseControllerは継承されていないため、単独のコントローラー
+    let standalone = find(&endpoints, "BaseController", "standalone")
+        .expect("継承されていないBaseControllerが検出されていません");
+    assert_eq!(standalone.path, "/y-standalone");
+    assert_eq!(
+        standalone.qualified_class_name,
+        "com.example.y.BaseController"
+    );
+
+    assert_eq!(endpoints.len(), 2);
+}
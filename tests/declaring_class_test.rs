@@ -0,0 +1,116 @@
+use path_finder::{extract_file_report, scan_directory_report};
+
+const RESOURCES: &str = "tests/resources_declaring_class";
+
+// (パス, クラス名, パッケージ, 完全修飾名) の一覧
+fn declaring_classes(endpoints: &[path_finder::Endpoint]) -> Vec<(&str, &str, Option<&str>, &str)> {
+    endpoints
+        .iter()
+        .map(|endpoint| {
+            (
+                endpoint.path.as_str(),
+                endpoint.class_name.as_str(),
+                endpoint.package.as_deref(),
+                endpoint.qualified_class_name.as_str(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_package_and_qualified_class_name() {
+    let report = scan_directory_report(RESOURCES).unwrap();
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+
+    // 同じクラス名のコントローラーは完全修飾名で区別できる
+    // 親クラスから継承したエンドポイントは親クラス、インターフェースから継承したものは実装クラスになる
+    assert_eq!(
+        declaring_classes(&report.endpoints),
+        vec![
+            (
+                "/no-package",
+                "NoPackageController",
+                None,
+                "NoPackageController"
+            ),
+            (
+                "/admin/orders",
+                "OrderAdminController",
+                Some("com.example.orders"),
+                "com.example.orders.AdminControllers.OrderAdminController"
+            ),
+            (
+                "/orders",
+                "OrderController",
+                Some("com.example.orders"),
+                "com.example.orders.OrderController"
+            ),
+            (
+                "/orders/version",
+                "BaseController",
+                Some("com.example.shared"),
+                "com.example.shared.BaseController"
+            ),
+            (
+                "/orders/status",
+                "StatusController",
+                Some("com.example.orders"),
+                "com.example.orders.StatusController"
+            ),
+            (
+                "/users/status",
+                "StatusController",
+                Some("com.example.users"),
+                "com.example.users.StatusController"
+            ),
+            (
+                "/users/ping",
+                "StatusController",
+                Some("com.example.users"),
+                "com.example.users.StatusController"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_package_in_json() {
+    let report = scan_directory_report(RESOURCES).unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+    let endpoints = json["endpoints"].as_array().unwrap();
+
+    // package宣言がない場合はpackageを出力しない
+    assert!(endpoints[0].get("package").is_none());
+    assert_eq!(endpoints[0]["qualified_class_name"], "NoPackageController");
+    assert_eq!(endpoints[2]["package"], "com.example.orders");
+    assert_eq!(
+        endpoints[2]["qualified_class_name"],
+        "com.example.orders.OrderController"
+    );
+}
+
+#[test]
+fn test_single_file() {
+    let report = extract_file_report(
+        "tests/resources_declaring_class/com/example/users/StatusController.kt",
+        RESOURCES,
+    )
+    .unwrap();
+    assert_eq!(
+        declaring_classes(&report.endpoints),
+        vec![
+            (
+                "/users/status",
+                "StatusController",
+                Some("com.example.users"),
+                "com.example.users.StatusController"
+            ),
+            (
+                "/users/ping",
+                "StatusController",
+                Some("com.example.users"),
+                "com.example.users.StatusController"
+            ),
+        ]
+    );
+}
@@ -0,0 +1,115 @@
+use path_finder::class_index::{ClassIndex, Resolution, TypeScope};
+use path_finder::constants::ConstantTable;
+use path_finder::diagnostics::DiagnosticKind;
+use path_finder::{scan_directory, scan_directory_json};
+use serde_json::Value;
+
+fn scan_json() -> Value {
+    let json = scan_directory_json("tests/resources_diagnostics").unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn find_diagnostic<'a>(value: &'a Value, kind: &str) -> &'a Value {
+    value["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|diagnostic| diagnostic["kind"] == kind)
+        .unwrap_or_else(|| panic!("{}の診断結果がありません", kind))
+}
+
+#[test]
+fn test_unreadable_file_does_not_abort_scan() {
+    // UTF-8でないファイルがあっても、他のファイルのエンドポイントは抽出される
+    let endpoints = scan_directory("tests/resources_diagnostics").unwrap();
+    let methods: Vec<&str> = endpoints
+        .iter()
+        .map(|endpoint| endpoint.method_name.as_str())
+        .collect();
+    assert_eq!(methods, vec!["ok", "missingParen", "getOrder"]);
+
+    let value = scan_json();
+    let read_error = find_diagnostic(&value, "read_error");
+    assert_eq!(read_error["severity"], "error");
+    assert_eq!(
+        read_error["file_path"],
+        "tests/resources_diagnostics/Latin1Controller.java"
+    );
+    assert!(read_error["message"]
+        .as_str()
+        .unwrap()
+        .contains("not valid UTF-8"));
+}
+
+#[test]
+fn test_unreadable_file_does_not_abort_indexing() {
+    // クラス宣言・定数定義の収集でも、読み込めないファイルはスキップして報告する
+    let mut diagnostics = Vec::new();
+    let index = ClassIndex::from_directory("tests/resources_diagnostics", &mut diagnostics);
+    let scope = TypeScope {
+        package: Some("com.example.diagnostics".to_string()),
+        ..TypeScope::default()
+    };
+    for class_name in ["BrokenAnnotationController", "OrderController"] {
+        assert!(matches!(
+            index.resolve(class_name, &scope),
+            Resolution::Found(_)
+        ));
+    }
+    assert!(matches!(
+        index.resolve("Latin1Controller", &scope),
+        Resolution::NotFound
+    ));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::ReadError);
+    assert_eq!(
+        diagnostics[0].file_path,
+        "tests/resources_diagnostics/Latin1Controller.java"
+    );
+
+    let mut diagnostics = Vec::new();
+    ConstantTable::from_directory("tests/resources_diagnostics", &mut diagnostics);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::ReadError);
+}
+
+#[test]
+fn test_syntax_error_near_annotation_is_reported() {
+    let value = scan_json();
+    let syntax_error = find_diagnostic(&value, "syntax_error");
+    assert_eq!(syntax_error["severity"], "warning");
+    assert_eq!(
+        syntax_error["file_path"],
+        "tests/resources_diagnostics/BrokenAnnotationController.java"
+    );
+    assert_eq!(syntax_error["line"], 13);
+}
+
+#[test]
+fn test_unresolved_parent_is_reported() {
+    let value = scan_json();
+    let unresolved = find_diagnostic(&value, "unresolved_parent");
+    assert_eq!(unresolved["severity"], "warning");
+    assert_eq!(
+        unresolved["file_path"],
+        "tests/resources_diagnostics/OrderController.kt"
+    );
+    assert_eq!(
+        unresolved["message"],
+        "Parent class MissingBaseController not found for OrderController"
+    );
+}
+
+#[test]
+fn test_json_output_has_separate_sections() {
+    let value = scan_json();
+    assert_eq!(value["endpoints"].as_array().unwrap().len(), 3);
+    assert_eq!(value["diagnostics"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_clean_directory_has_no_diagnostics() {
+    let json = scan_directory_json("tests/resources_media_types").unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert!(value["diagnostics"].as_array().unwrap().is_empty());
+}
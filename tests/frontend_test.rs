@@ -0,0 +1,255 @@
+use anyhow::{Context, Result};
+use path_finder::annotation::{Annotation, AnnotationValue};
+use path_finder::class_index::TypeScope;
+use path_finder::constants::{ConstantDefinition, ConstantExpr};
+use path_finder::diagnostics::DiagnosticKind;
+use path_finder::frontend::{
+    FrontendRegistry, LanguageFrontend, MethodDeclaration, TypeDeclaration,
+};
+use path_finder::{
+    scan_directory_report_with_options, Endpoint, ScanOptions, Supertype, SupertypeKind,
+};
+use std::path::Path;
+
+const RESOURCES: &str = "tests/resources_frontend";
+
+// テスト用の言語: 1行目に`package`、2行目に`controller クラス名 パス [extends 親クラス]`、
+// 3行目以降に`HTTPメソッド パス メソッド名`を書く
+struct RoutesFrontend;
+
+struct RoutesFile<'a> {
+    package: &'a str,
+    class_name: &'a str,
+    base_path: &'a str,
+    parent: Option<&'a str>,
+    // (行番号, HTTPメソッド, パス, メソッド名)
+    routes: Vec<(usize, &'a str, &'a str, &'a str)>,
+}
+
+fn parse(source_code: &str) -> Result<RoutesFile<'_>> {
+    let mut lines = source_code.lines();
+    let package = lines
+        .next()
+        .and_then(|line| line.strip_prefix("package "))
+        .context("package宣言がありません")?;
+    let declaration: Vec<&str> = lines
+        .next()
+        .and_then(|line| line.strip_prefix("controller "))
+        .context("controller宣言がありません")?
+        .split_whitespace()
+        .collect();
+    let routes = lines
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [http_method, path, method_name] => {
+                    Some((index + 3, http_method, path, method_name))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    Ok(RoutesFile {
+        package,
+        class_name: declaration[0],
+        base_path: declaration[1],
+        parent: (declaration.get(2) == Some(&"extends")).then(|| declaration[3]),
+        routes,
+    })
+}
+
+// Springのマッピングアノテーションを付与した宣言として表す
+fn annotation(name: &str, value: &str) -> Annotation {
+    let mut annotation = Annotation::new(name, &[]);
+    annotation.args.insert(
+        "value".to_string(),
+        AnnotationValue::Expr {
+            text: format!("\"{}\"", value),
+            expr: Some(ConstantExpr::Literal(value.to_string())),
+        },
+    );
+    annotation
+}
+
+fn mapping_annotation(http_method: &str) -> &'static str {
+    match http_method {
+        "POST" => "PostMapping",
+        "PUT" => "PutMapping",
+        "DELETE" => "DeleteMapping",
+        "PATCH" => "PatchMapping",
+        _ => "GetMapping",
+    }
+}
+
+impl LanguageFrontend for RoutesFrontend {
+    fn name(&self) -> &str {
+        "Routes"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["routes"]
+    }
+
+    fn extract_types(&self, file_path: &str, source_code: &str) -> Result<Vec<TypeDeclaration>> {
+        let routes = parse(source_code)?;
+        Ok(vec![TypeDeclaration {
+            name: routes.class_name.to_string(),
+            scope: TypeScope {
+                file_path: file_path.to_string(),
+                package: Some(routes.package.to_string()),
+                ..TypeScope::default()
+            },
+            byte_range: (0, source_code.len()),
+            is_interface: false,
+            supertypes: routes
+                .parent
+                .map(|parent| Supertype {
+                    name: parent.to_string(),
+                    kind: SupertypeKind::Class,
+                })
+                .into_iter()
+                .collect(),
+            annotations: vec![annotation("RequestMapping", routes.base_path)],
+            methods: routes
+                .routes
+                .iter()
+                .map(
+                    |&(line, http_method, path, method_name)| MethodDeclaration {
+                        name: method_name.to_string(),
+                        line_range: (line, line),
+                        annotations: vec![annotation(mapping_annotation(http_method), path)],
+                        parameters: Vec::new(),
+                    },
+                )
+                .collect(),
+        }])
+    }
+
+    fn extract_constants(
+        &self,
+        _file_path: &str,
+        _source_code: &str,
+    ) -> Result<Vec<ConstantDefinition>> {
+        Ok(Vec::new())
+    }
+}
+
+// (パス, HTTPメソッド, クラス名, 完全修飾名) の一覧
+fn summary(endpoints: &[Endpoint]) -> Vec<(&str, &str, &str, &str)> {
+    endpoints
+        .iter()
+        .map(|endpoint| {
+            (
+                endpoint.path.as_str(),
+                endpoint.http_method.as_str(),
+                endpoint.class_name.as_str(),
+                endpoint.qualified_class_name.as_str(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_registered_frontend_is_scanned_with_builtin_frontends() {
+    let options = ScanOptions {
+        frontends: FrontendRegistry::default().register(RoutesFrontend),
+        ..ScanOptions::default()
+    };
+    let report = scan_directory_report_with_options(RESOURCES, &options).unwrap();
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+
+    // 追加した言語のコントローラーも、Javaの親クラスから継承したエンドポイントも抽出する
+    assert_eq!(
+        summary(&report.endpoints),
+        vec![
+            (
+                "/health/live",
+                "GET",
+                "HealthController",
+                "com.example.HealthController"
+            ),
+            (
+                "/health/ready",
+                "GET",
+                "HealthController",
+                "com.example.HealthController"
+            ),
+            (
+                "/health/status",
+                "GET",
+                "StatusEndpoints",
+                "com.example.StatusEndpoints"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_only_registered_frontends_are_used() {
+    let options = ScanOptions {
+        frontends: FrontendRegistry::empty().register(RoutesFrontend),
+        ..ScanOptions::default()
+    };
+    let report = scan_directory_report_with_options(RESOURCES, &options).unwrap();
+
+    // Javaのフロントエンドを登録していないため、Javaの親クラスは見つからない
+    assert_eq!(
+        summary(&report.endpoints),
+        vec![
+            (
+                "/health/live",
+                "GET",
+                "HealthController",
+                "com.example.HealthController"
+            ),
+            (
+                "/health/ready",
+                "GET",
+                "HealthController",
+                "com.example.HealthController"
+            ),
+        ]
+    );
+    assert_eq!(report.diagnostics.len(), 1, "{:?}", report.diagnostics);
+    assert_eq!(report.diagnostics[0].kind, DiagnosticKind::UnresolvedParent);
+}
+
+#[test]
+fn test_later_registration_takes_precedence() {
+    let registry = FrontendRegistry::default().register(RoutesFrontend);
+    let frontend_name = |file_name: &str| {
+        registry
+            .for_path(Path::new(file_name))
+            .map(|frontend| frontend.name().to_string())
+    };
+
+    assert_eq!(frontend_name("Order.java").as_deref(), Some("Java"));
+    assert_eq!(frontend_name("Order.kt").as_deref(), Some("Kotlin"));
+    assert_eq!(frontend_name("health.routes").as_deref(), Some("Routes"));
+    assert_eq!(frontend_name("build.gradle"), None);
+
+    // 同じ拡張子のフロントエンドは後から登録したものを使う
+    struct ReplacedJava;
+    impl LanguageFrontend for ReplacedJava {
+        fn name(&self) -> &str {
+            "ReplacedJava"
+        }
+        fn extensions(&self) -> &[&str] {
+            &["java"]
+        }
+        fn extract_types(&self, _: &str, _: &str) -> Result<Vec<TypeDeclaration>> {
+            Ok(Vec::new())
+        }
+        fn extract_constants(&self, _: &str, _: &str) -> Result<Vec<ConstantDefinition>> {
+            Ok(Vec::new())
+        }
+    }
+    let registry = registry.register(ReplacedJava);
+    assert_eq!(
+        registry
+            .for_path(Path::new("Order.java"))
+            .map(|frontend| frontend.name()),
+        Some("ReplacedJava")
+    );
+}
@@ -0,0 +1,73 @@
+use path_finder::diagnostics::DiagnosticKind;
+use path_finder::{scan_directory, scan_directory_report, Endpoint};
+
+fn methods_of(endpoints: &[Endpoint], class_name: &str, method_name: &str) -> Vec<String> {
+    endpoints
+        .iter()
+        .filter(|e| e.class_name == class_name && e.method_name == method_name)
+        .map(|e| e.http_method.clone())
+        .collect()
+}
+
+#[test]
+fn test_java_multiple_request_methods() {
+    let endpoints = scan_directory("tests/resources_http_methods").unwrap();
+
+    // method = {GET, HEAD} はメソッドごとにエンドポイントを出力
+    assert_eq!(
+        methods_of(&endpoints, "ResourceController", "getResource"),
+        vec!["GET", "HEAD"]
+    );
+    assert!(endpoints
+        .iter()
+        .filter(|e| e.method_name == "getResource")
+        .all(|e| e.path == "/resources/{id}"));
+
+    // static importされたRequestMethod
+    assert_eq!(
+        methods_of(&endpoints, "ResourceController", "options"),
+        vec!["OPTIONS"]
+    );
+    assert_eq!(
+        methods_of(&endpoints, "ResourceController", "trace"),
+        vec!["TRACE"]
+    );
+
+    // method属性がない場合はANY
+    assert_eq!(
+        methods_of(&endpoints, "ResourceController", "any"),
+        vec!["ANY"]
+    );
+}
+
+#[test]
+fn test_non_request_method_is_reported() {
+    let report = scan_directory_report("tests/resources_http_methods").unwrap();
+
+    // RequestMethodの定数だけをHTTPメソッドとして扱う
+    assert_eq!(
+        methods_of(&report.endpoints, "ResourceController", "custom"),
+        vec!["GET"]
+    );
+
+    assert_eq!(report.diagnostics.len(), 1, "{:?}", report.diagnostics);
+    let diagnostic = &report.diagnostics[0];
+    assert_eq!(diagnostic.kind, DiagnosticKind::UnsupportedRequestMethod);
+    assert_eq!(diagnostic.class_name.as_deref(), Some("ResourceController"));
+    assert_eq!(diagnostic.line, Some(27));
+    assert!(diagnostic.message.contains("HttpMethods.CUSTOM"));
+}
+
+#[test]
+fn test_kotlin_multiple_request_methods() {
+    let endpoints = scan_directory("tests/resources_http_methods").unwrap();
+
+    assert_eq!(
+        methods_of(&endpoints, "DocumentController", "getDocument"),
+        vec!["GET", "HEAD"]
+    );
+    assert_eq!(
+        methods_of(&endpoints, "DocumentController", "updateDocuments"),
+        vec!["PUT", "PATCH"]
+    );
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::find_by_path;
+use path_finder::class_index::{ClassIndex, Import, Resolution, TypeScope};
+use path_finder::scan_directory;
+
+fn import(path: &str, wildcard: bool, alias: Option<&str>) -> Import {
+    Import {
+        path: path.to_string(),
+        wildcard,
+        alias: alias.map(|alias| alias.to_string()),
+    }
+}
+
+#[test]
+fn test_parent_resolved_through_package_and_imports() {
+    let endpoints = scan_directory("tests/resources_imports").unwrap();
+
+    // 同じパッケージ
+    let same_package = find_by_path(&endpoints, "/same-package/shop-health")
+        .expect("同じパッケージの親クラスが解決されていません");
+    assert!(same_package.file_path.ends_with("shop/BaseController.java"));
+
+    // 単一型のimport
+    assert!(find_by_path(&endpoints, "/single-import/admin-health").is_some());
+    assert!(find_by_path(&endpoints, "/single-import/shop-health").is_none());
+
+    // ワイルドカードのimport
+    assert!(find_by_path(&endpoints, "/wildcard-import/shop-health").is_some());
+    assert!(find_by_path(&endpoints, "/kotlin-wildcard/admin-health").is_some());
+
+    // 完全修飾名
+    assert!(find_by_path(&endpoints, "/qualified/admin-health").is_some());
+
+    // Kotlinのimportの別名
+    let alias = find_by_path(&endpoints, "/alias/shop-health")
+        .expect("別名でimportした親クラスが解決されていません");
+    assert!(alias.file_path.ends_with("shop/BaseController.java"));
+}
+
+#[test]
+fn test_ambiguous_parent_is_not_guessed() {
+    let endpoints = scan_directory("tests/resources_imports").unwrap();
+
+    // 自クラスのエンドポイントのみ出力し、どちらかの親クラスを推測して継承しない
+    assert!(find_by_path(&endpoints, "/ambiguous/own").is_some());
+    assert!(!endpoints
+        .iter()
+        .any(|e| e.path.starts_with("/ambiguous/") && e.method_name == "health"));
+
+    assert_eq!(endpoints.len(), 8);
+}
+
+#[test]
+fn test_class_index_resolve() {
+    let index = ClassIndex::from_directory("tests/resources_imports", &mut Vec::new());
+
+    let scope = TypeScope {
+        file_path: "Example.kt".to_string(),
+        package: Some("com.example.other".to_string()),
+        imports: vec![
+            import("com.example.admin", true, None),
+            import("com.example.shop.BaseController", false, Some("ShopBase")),
+        ],
+        static_imports: Vec::new(),
+        enclosing_class: None,
+    };
+
+    // 別名
+    match index.resolve("ShopBase", &scope) {
+        Resolution::Found(entry) => {
+            assert_eq!(entry.qualified_name(), "com.example.shop.BaseController")
+        }
+        other => panic!("ShopBaseが解決されていません: {:?}", other),
+    }
+
+    // 別名でimportしたクラスは元の名前では参照されないため、ワイルドカードのimportから解決する
+    match index.resolve("BaseController", &scope) {
+        Resolution::Found(entry) => {
+            assert_eq!(entry.qualified_name(), "com.example.admin.BaseController")
+        }
+        other => panic!("BaseControllerが解決されていません: {:?}", other),
+    }
+
+    // import宣言から辿れない場合は、同名のクラスがあっても推測しない
+    assert!(matches!(
+        index.resolve("BaseController", &TypeScope::default()),
+        Resolution::NotFound
+    ));
+
+    // インデックスにないクラスをimportしている
+    let external = TypeScope {
+        imports: vec![import(
+            "org.springframework.web.BaseController",
+            false,
+            None,
+        )],
+        ..TypeScope::default()
+    };
+    assert!(matches!(
+        index.resolve("BaseController", &external),
+        Resolution::NotFound
+    ));
+}
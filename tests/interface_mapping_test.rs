@@ -0,0 +1,83 @@
+use path_finder::{scan_directory, Endpoint};
+
+fn find<'a>(endpoints: &'a [Endpoint], method_name: &str) -> Option<&'a Endpoint> {
+    endpoints.iter().find(|e| e.method_name == method_name)
+}
+
+#[test]
+fn test_java_interface_mappings() {
+    let endpoints = scan_directory("tests/resources_interface").unwrap();
+
+    // インターフェースで宣言されたマッピングは実装クラスのパスと結合され、実装クラスのものとして扱う
+    let list_pets = find(&endpoints, "listPets").expect("listPetsが見つかりません");
+    assert_eq!(list_pets.class_name, "PetController");
+    assert_eq!(list_pets.http_method, "GET");
+    assert_eq!(list_pets.path, "/v1/pets");
+    assert!(list_pets.file_path.ends_with("PetApi.java"));
+
+    // 複数のインターフェースを実装している場合
+    let delete_pet = find(&endpoints, "deletePet").expect("deletePetが見つかりません");
+    assert_eq!(delete_pet.class_name, "PetController");
+    assert_eq!(delete_pet.path, "/v1/pets/{id}");
+
+    // インターフェースの継承チェーン
+    let version = find(&endpoints, "version").expect("versionが見つかりません");
+    assert_eq!(version.class_name, "PetController");
+    assert_eq!(version.path, "/v1/version");
+}
+
+#[test]
+fn test_kotlin_interface_mappings() {
+    let endpoints = scan_directory("tests/resources_interface").unwrap();
+
+    let place_order = find(&endpoints, "placeOrder").expect("placeOrderが見つかりません");
+    assert_eq!(place_order.class_name, "StoreController");
+    assert_eq!(place_order.http_method, "POST");
+    assert_eq!(place_order.path, "/store/orders");
+
+    let inventory = find(&endpoints, "inventory").expect("inventoryが見つかりません");
+    assert_eq!(inventory.class_name, "StoreController");
+    assert_eq!(inventory.path, "/store/inventory");
+}
+
+#[test]
+fn test_interfaces_are_not_reported_as_controllers() {
+    let endpoints = scan_directory("tests/resources_interface").unwrap();
+
+    assert_eq!(endpoints.len(), 9);
+    assert!(endpoints.iter().all(|e| [
+        "PetController",
+        "StoreController",
+        "OrderController",
+        "ShippingController"
+    ]
+    .contains(&e.class_name.as_str())));
+}
+
+#[test]
+fn test_class_mapping_is_inherited_from_interface() {
+    let endpoints = scan_directory("tests/resources_interface").unwrap();
+
+    // 実装クラスに@RequestMappingがない場合は、インターフェースのクラスレベルのマッピングを使う
+    let get_order = find(&endpoints, "getOrder").expect("getOrderが見つかりません");
+    assert_eq!(get_order.class_name, "OrderController");
+    assert_eq!(get_order.path, "/api/orders/{id}");
+
+    let rates = find(&endpoints, "rates").expect("ratesが見つかりません");
+    assert_eq!(rates.class_name, "ShippingController");
+    assert_eq!(rates.path, "/shipping/rates");
+}
+
+#[test]
+fn test_remapped_interface_method_is_not_duplicated() {
+    let endpoints = scan_directory("tests/resources_interface").unwrap();
+
+    // 実装クラスでマッピングし直したメソッドは、実装クラスのマッピングだけを使う
+    let list_orders: Vec<_> = endpoints
+        .iter()
+        .filter(|e| e.method_name == "listOrders")
+        .collect();
+    assert_eq!(list_orders.len(), 1);
+    assert_eq!(list_orders[0].path, "/api/orders/all");
+    assert!(list_orders[0].file_path.ends_with("OrderController.java"));
+}
@@ -0,0 +1,79 @@
+use path_finder::frontend::FrontendRegistry;
+use path_finder::{scan_directory, Endpoint, Supertype, SupertypeKind};
+use std::fs;
+use std::path::Path;
+
+fn find<'a>(endpoints: &'a [Endpoint], method_name: &str) -> Option<&'a Endpoint> {
+    endpoints.iter().find(|e| e.method_name == method_name)
+}
+
+fn supertype(name: &str, kind: SupertypeKind) -> Supertype {
+    Supertype {
+        name: name.to_string(),
+        kind,
+    }
+}
+
+// ファイル内の各クラスについて、継承・実装している型を列挙する
+fn extract_supertypes(file_path: &str) -> Vec<(String, Vec<Supertype>)> {
+    let source_code = fs::read_to_string(file_path).unwrap();
+    FrontendRegistry::default()
+        .for_path(Path::new(file_path))
+        .unwrap()
+        .extract_types(file_path, &source_code)
+        .unwrap()
+        .into_iter()
+        .map(|declaration| (declaration.name, declaration.supertypes))
+        .collect()
+}
+
+#[test]
+fn test_kotlin_supertypes_are_extracted_from_ast() {
+    let supertypes = extract_supertypes("tests/resources_kotlin_supertypes/ProductController.kt");
+
+    // 型引数を除き、コンストラクタ呼び出しの有無で親クラスとインターフェースを区別する
+    assert_eq!(
+        supertypes,
+        vec![(
+            "ProductController".to_string(),
+            vec![
+                supertype("GenericBaseController", SupertypeKind::Class),
+                supertype("Auditable", SupertypeKind::Interface),
+                supertype("com.example.supertypes.Trackable", SupertypeKind::Interface),
+            ]
+        )]
+    );
+    assert_eq!(supertypes[0].1[2].simple_name(), "Trackable");
+}
+
+#[test]
+fn test_kotlin_property_type_is_not_a_supertype() {
+    // プロパティ宣言の「 : 」を継承と誤認しない
+    let supertypes = extract_supertypes("tests/resources_kotlin_supertypes/LabelController.kt");
+
+    assert_eq!(supertypes, vec![("LabelController".to_string(), vec![])]);
+}
+
+#[test]
+fn test_kotlin_endpoints_from_all_supertypes() {
+    let endpoints = scan_directory("tests/resources_kotlin_supertypes").unwrap();
+
+    // ジェネリクスの親クラス
+    let find_by_id = find(&endpoints, "findById").expect("findByIdが見つかりません");
+    assert_eq!(find_by_id.path, "/products/{id}");
+    assert!(find_by_id.file_path.ends_with("GenericBaseController.kt"));
+
+    // 2つ目以降のスーパータイプ（パッケージ修飾・型引数付きを含む）
+    let audit = find(&endpoints, "audit").expect("auditが見つかりません");
+    assert_eq!(audit.class_name, "ProductController");
+    assert_eq!(audit.path, "/products/audit");
+
+    let tracking = find(&endpoints, "tracking").expect("trackingが見つかりません");
+    assert_eq!(tracking.class_name, "ProductController");
+    assert_eq!(tracking.path, "/products/tracking");
+
+    let label = find(&endpoints, "label").expect("labelが見つかりません");
+    assert_eq!(label.path, "/labels");
+
+    assert_eq!(endpoints.len(), 5);
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::find;
+use path_finder::{scan_directory, scan_directory_json};
+
+#[test]
+fn test_java_consumes_and_produces() {
+    let endpoints = scan_directory("tests/resources_media_types").unwrap();
+
+    // クラスレベルのproducesが引き継がれる
+    let get_report = find(&endpoints, "ReportController", "getReport").unwrap();
+    assert!(get_report.consumes.is_empty());
+    assert_eq!(get_report.produces, vec!["application/json"]);
+
+    // MediaTypeの定数を解決する
+    let create_report = find(&endpoints, "ReportController", "createReport").unwrap();
+    assert_eq!(
+        create_report.consumes,
+        vec!["application/json", "application/xml"]
+    );
+
+    // メソッドレベルのproducesがクラスレベルより優先される（自クラスの定数・static importに対応）
+    let export_report = find(&endpoints, "ReportController", "exportReport").unwrap();
+    assert_eq!(export_report.produces, vec!["text/csv", "text/plain"]);
+}
+
+#[test]
+fn test_kotlin_consumes_and_produces() {
+    let endpoints = scan_directory("tests/resources_media_types").unwrap();
+
+    let upload = find(&endpoints, "UploadController", "upload").unwrap();
+    assert_eq!(upload.consumes, vec!["multipart/form-data"]);
+    assert_eq!(upload.produces, vec!["application/json"]);
+
+    let get_upload = find(&endpoints, "UploadController", "getUpload").unwrap();
+    assert!(get_upload.consumes.is_empty());
+    assert!(get_upload.produces.is_empty());
+}
+
+#[test]
+fn test_media_types_in_json_output() {
+    let json = scan_directory_json("tests/resources_media_types").unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let upload = value["endpoints"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["method_name"] == "upload")
+        .unwrap();
+    assert_eq!(
+        upload["consumes"],
+        serde_json::json!(["multipart/form-data"])
+    );
+    assert_eq!(upload["produces"], serde_json::json!(["application/json"]));
+}
@@ -0,0 +1,186 @@
+use path_finder::config::Config;
+use path_finder::diagnostics::DiagnosticKind;
+use path_finder::missing_parent::MissingParentPolicy;
+use path_finder::{scan_directory_report_with_options, ScanOptions};
+use std::path::Path;
+
+const RESOURCES: &str = "tests/resources_missing_parents";
+
+fn patterns(patterns: &[&str]) -> Vec<String> {
+    patterns.iter().map(|pattern| pattern.to_string()).collect()
+}
+
+// 親クラスが見つからないと警告されたクラス名を返す
+fn warned_classes(missing_parents: MissingParentPolicy) -> Vec<String> {
+    let report = scan_directory_report_with_options(
+        RESOURCES,
+        &ScanOptions {
+            missing_parents,
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(report.endpoints.len(), 3);
+
+    report
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.kind == DiagnosticKind::UnresolvedParent)
+        .filter_map(|diagnostic| diagnostic.class_name.clone())
+        .collect()
+}
+
+#[test]
+fn test_default_suppressions() {
+    // BaseControllerは組み込みのリストで抑制される
+    assert_eq!(
+        warned_classes(MissingParentPolicy::default()),
+        vec!["FrameworkController", "PlatformController"]
+    );
+
+    // 組み込みのリストを使わない場合はすべて警告する
+    assert_eq!(
+        warned_classes(MissingParentPolicy {
+            suppress_defaults: false,
+            ..MissingParentPolicy::default()
+        }),
+        vec![
+            "FrameworkController",
+            "OwnBaseController",
+            "PlatformController"
+        ]
+    );
+}
+
+#[test]
+fn test_suppress_by_qualified_name() {
+    // import宣言から完全修飾名を求めて照合するため、自分たちのクラスは抑制されない
+    assert_eq!(
+        warned_classes(MissingParentPolicy {
+            suppress: patterns(&["org.springframework.*"]),
+            ..MissingParentPolicy::default()
+        }),
+        vec!["PlatformController"]
+    );
+
+    // 同じパッケージのクラスとして照合する（import宣言がない場合）
+    assert_eq!(
+        warned_classes(MissingParentPolicy {
+            suppress: patterns(&["com.example.web.*"]),
+            suppress_defaults: false,
+            ..MissingParentPolicy::default()
+        }),
+        vec!["FrameworkController", "PlatformController"]
+    );
+}
+
+#[test]
+fn test_suppress_by_simple_name_glob() {
+    assert_eq!(
+        warned_classes(MissingParentPolicy {
+            suppress: patterns(&["*Resource", "WebContent*"]),
+            ..MissingParentPolicy::default()
+        }),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn test_warn_overrides_suppress() {
+    // 組み込みのリストにあるクラス名でも警告する
+    assert_eq!(
+        warned_classes(MissingParentPolicy {
+            warn: patterns(&["BaseController"]),
+            ..MissingParentPolicy::default()
+        }),
+        vec![
+            "FrameworkController",
+            "OwnBaseController",
+            "PlatformController"
+        ]
+    );
+
+    // 抑制するパターンより警告するパターンを優先する
+    assert_eq!(
+        warned_classes(MissingParentPolicy {
+            suppress: patterns(&["com.example.*", "org.*"]),
+            warn: patterns(&["com.example.platform.*"]),
+            ..MissingParentPolicy::default()
+        }),
+        vec!["PlatformController"]
+    );
+}
+
+#[test]
+fn test_policy_from_config_file() {
+    let config = Config::load(Path::new(
+        "tests/resources_missing_parents/missing-parents.toml",
+    ))
+    .unwrap();
+    assert_eq!(
+        config.missing_parents.suppress,
+        vec!["org.springframework.*"]
+    );
+    assert_eq!(config.missing_parents.warn, vec!["BaseController"]);
+    assert!(config.missing_parents.suppress_defaults);
+
+    // CLIの指定は設定ファイルのパターンに追加される
+    let mut missing_parents = config.missing_parents;
+    missing_parents.merge(MissingParentPolicy {
+        suppress: patterns(&["com.example.platform.*"]),
+        ..MissingParentPolicy::default()
+    });
+    assert_eq!(warned_classes(missing_parents), vec!["OwnBaseController"]);
+}
+
+#[test]
+fn test_invalid_pattern_is_reported() {
+    let result = scan_directory_report_with_options(
+        RESOURCES,
+        &ScanOptions {
+            missing_parents: MissingParentPolicy {
+                suppress: patterns(&["Base[Controller"]),
+                ..MissingParentPolicy::default()
+            },
+            ..ScanOptions::default()
+        },
+    );
+
+    let error = format!("{:#}", result.unwrap_err());
+    assert!(error.contains("Base[Controller"), "{}", error);
+}
+
+#[test]
+fn test_changed_policy_is_not_served_from_cache() {
+    let cache_dir = std::env::temp_dir().join(format!(
+        "path-finder-missing-parent-test-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    let scan = |missing_parents: MissingParentPolicy| {
+        scan_directory_report_with_options(
+            RESOURCES,
+            &ScanOptions {
+                cache_dir: Some(cache_dir.clone()),
+                missing_parents,
+                ..ScanOptions::default()
+            },
+        )
+        .unwrap()
+        .diagnostics
+        .len()
+    };
+
+    assert_eq!(scan(MissingParentPolicy::default()), 2);
+    assert_eq!(
+        scan(MissingParentPolicy {
+            suppress: patterns(&["*"]),
+            ..MissingParentPolicy::default()
+        }),
+        0
+    );
+    assert_eq!(scan(MissingParentPolicy::default()), 2);
+
+    let _ = std::fs::remove_dir_all(&cache_dir);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::paths_of;
+use path_finder::scan_directory;
+
+#[test]
+fn test_java_multi_value_paths() {
+    let endpoints = scan_directory("tests/resources_multi_path").unwrap();
+
+    // クラスのパスとメソッドのパスの直積
+    assert_eq!(
+        paths_of(&endpoints, "CatalogController", "listItems"),
+        vec![
+            "/catalog/items",
+            "/catalog/products",
+            "/shop/items",
+            "/shop/products"
+        ]
+    );
+    assert_eq!(
+        paths_of(&endpoints, "CatalogController", "createItem"),
+        vec!["/catalog/items", "/shop/items"]
+    );
+
+    // メソッドのパスがない場合はクラスのパスのみ
+    assert_eq!(
+        paths_of(&endpoints, "CatalogController", "index"),
+        vec!["/catalog", "/shop"]
+    );
+
+    // クラスのパスがない場合はメソッドのパスのみ
+    assert_eq!(
+        paths_of(&endpoints, "AliasController", "status"),
+        vec!["/status", "/health"]
+    );
+}
+
+#[test]
+fn test_kotlin_multi_value_paths() {
+    let endpoints = scan_directory("tests/resources_multi_path").unwrap();
+
+    assert_eq!(
+        paths_of(&endpoints, "CartController", "listLines"),
+        vec![
+            "/cart/lines",
+            "/cart/entries",
+            "/basket/lines",
+            "/basket/entries"
+        ]
+    );
+    assert_eq!(
+        paths_of(&endpoints, "CartController", "deleteLine"),
+        vec!["/cart/lines/{id}", "/basket/lines/{id}"]
+    );
+}
+
+#[test]
+fn test_inherited_methods_use_all_class_paths() {
+    let endpoints = scan_directory("tests/resources_multi_path").unwrap();
+
+    assert_eq!(
+        paths_of(&endpoints, "PagingSupport", "page"),
+        vec!["/catalog/page", "/shop/page"]
+    );
+}
@@ -0,0 +1,56 @@
+use path_finder::{scan_directory_json_with_options, scan_directory_with_options, ScanOptions};
+
+#[test]
+fn test_output_does_not_depend_on_jobs() {
+    let sequential = scan_directory_json_with_options(
+        "tests/resources_inherit",
+        &ScanOptions {
+            jobs: Some(1),
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+
+    // スレッド数が異なっても、何度実行しても同じ順序で出力される
+    for jobs in [Some(2), Some(8), None] {
+        for _ in 0..3 {
+            let parallel = scan_directory_json_with_options(
+                "tests/resources_inherit",
+                &ScanOptions {
+                    jobs,
+                    ..ScanOptions::default()
+                },
+            )
+            .unwrap();
+            assert_eq!(sequential, parallel, "jobs={:?}で出力が異なります", jobs);
+        }
+    }
+}
+
+#[test]
+fn test_endpoints_are_ordered_by_file_path() {
+    let endpoints = scan_directory_with_options(
+        "tests/resources_interface",
+        &ScanOptions {
+            jobs: Some(4),
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+
+    // 自クラスのエンドポイントは、コントローラーのファイルパス順に並ぶ
+    let mut controllers: Vec<&str> = endpoints
+        .iter()
+        .map(|endpoint| endpoint.class_name.as_str())
+        .collect();
+    controllers.dedup();
+    assert_eq!(
+        controllers,
+        vec![
+            "OrderController",
+            "PetController",
+            "ShippingController",
+            "StoreController"
+        ]
+    );
+}
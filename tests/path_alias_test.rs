@@ -0,0 +1,39 @@
+mod common;
+
+use common::paths_of;
+use path_finder::scan_directory;
+
+#[test]
+fn test_java_path_alias() {
+    let endpoints = scan_directory("tests/resources_path_alias").unwrap();
+
+    // クラスレベル・メソッドレベルの path 属性
+    assert_eq!(
+        paths_of(&endpoints, "ShipmentController", "getShipment"),
+        vec!["/shipments/{id}"]
+    );
+    assert_eq!(
+        paths_of(&endpoints, "ShipmentController", "track"),
+        vec!["/shipments/track", "/shipments/trace"]
+    );
+
+    // path 属性が他の属性の後に書かれている場合
+    assert_eq!(
+        paths_of(&endpoints, "ShipmentController", "bulkCreate"),
+        vec!["/shipments/bulk"]
+    );
+}
+
+#[test]
+fn test_kotlin_path_alias() {
+    let endpoints = scan_directory("tests/resources_path_alias").unwrap();
+
+    assert_eq!(
+        paths_of(&endpoints, "CarrierController", "getCarrier"),
+        vec!["/carriers/{code}"]
+    );
+    assert_eq!(
+        paths_of(&endpoints, "CarrierController", "deleteCarrier"),
+        vec!["/carriers/{code}", "/carriers/by-code/{code}"]
+    );
+}
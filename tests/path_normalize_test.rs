@@ -0,0 +1,72 @@
+mod common;
+
+use common::find;
+use path_finder::{scan_directory, url_path};
+
+#[test]
+fn test_join_paths() {
+    assert_eq!(url_path::join("/api/users", "{id}"), "/api/users/{id}");
+    assert_eq!(url_path::join("/api/", "/x"), "/api/x");
+    assert_eq!(url_path::join("/api//", "//x"), "/api/x");
+    assert_eq!(url_path::join("api", "x/"), "/api/x/");
+    assert_eq!(url_path::join("/api", ""), "/api");
+    assert_eq!(url_path::join("", "x"), "/x");
+    assert_eq!(url_path::join("", ""), "/");
+    assert_eq!(url_path::join(" ", ""), "/");
+}
+
+#[test]
+fn test_canonicalize_paths() {
+    assert_eq!(url_path::canonicalize("/api/users/"), "/api/users");
+    assert_eq!(url_path::canonicalize("api//users"), "/api/users");
+    assert_eq!(url_path::canonicalize(""), "/");
+    assert_eq!(url_path::canonicalize("/"), "/");
+}
+
+#[test]
+fn test_java_joined_paths_are_normalized() {
+    let endpoints = scan_directory("tests/resources_path_normalize").unwrap();
+
+    // メソッドのパスに先頭のスラッシュがない場合
+    let list_items = find(&endpoints, "SlashController", "listItems").unwrap();
+    assert_eq!(list_items.path, "/api/items");
+
+    // 末尾のスラッシュは宣言どおり残し、正規形では取り除く
+    let get_item = find(&endpoints, "SlashController", "getItem").unwrap();
+    assert_eq!(get_item.path, "/api/items/{id}/");
+    assert_eq!(get_item.canonical_path, "/api/items/{id}");
+
+    // 連続したスラッシュ
+    let bulk = find(&endpoints, "SlashController", "bulk").unwrap();
+    assert_eq!(bulk.path, "/api/bulk");
+
+    let root = find(&endpoints, "SlashController", "root").unwrap();
+    assert_eq!(root.path, "/api/");
+    assert_eq!(root.canonical_path, "/api");
+}
+
+#[test]
+fn test_kotlin_joined_paths_are_normalized() {
+    let endpoints = scan_directory("tests/resources_path_normalize").unwrap();
+
+    let daily = find(&endpoints, "NoLeadingSlashController", "daily").unwrap();
+    assert_eq!(daily.path, "/reports/daily");
+    assert_eq!(daily.canonical_path, "/reports/daily");
+
+    // パスの指定がない場合はルートになる
+    let index = find(&endpoints, "RootController", "index").unwrap();
+    assert_eq!(index.path, "/");
+    assert_eq!(index.canonical_path, "/");
+}
+
+#[test]
+fn test_empty_paths_are_root() {
+    let endpoints = scan_directory("tests/resources_path_normalize").unwrap();
+
+    // クラスにもメソッドにもパスがない場合（空文字列・空配列）は、pathもルートになる
+    for method_name in ["home", "submit"] {
+        let endpoint = find(&endpoints, "EmptyPathController", method_name).unwrap();
+        assert_eq!(endpoint.path, "/", "{}", method_name);
+        assert_eq!(endpoint.canonical_path, "/", "{}", method_name);
+    }
+}
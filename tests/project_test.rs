@@ -0,0 +1,139 @@
+use path_finder::project::{discover_source_roots, is_project_root, SourceRoot};
+use path_finder::{scan_source_roots_report, ScanOptions};
+use std::path::Path;
+
+const GRADLE_PROJECT: &str = "tests/resources_gradle_modules";
+const MAVEN_PROJECT: &str = "tests/resources_maven_modules";
+
+fn source_root(path: &str, module: &str) -> SourceRoot {
+    SourceRoot {
+        path: path.to_string(),
+        module: Some(module.to_string()),
+    }
+}
+
+// (パス, モジュール) の一覧
+fn endpoint_modules(roots: &[SourceRoot]) -> Vec<(String, Option<String>)> {
+    let report = scan_source_roots_report(roots, &ScanOptions::default()).unwrap();
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+    report
+        .endpoints
+        .iter()
+        .map(|endpoint| (endpoint.path.clone(), endpoint.module.clone()))
+        .collect()
+}
+
+fn endpoint_module(path: &str, module: &str) -> (String, Option<String>) {
+    (path.to_string(), Some(module.to_string()))
+}
+
+#[test]
+fn test_discover_gradle_modules() {
+    assert!(is_project_root(Path::new(GRADLE_PROJECT)));
+    assert_eq!(
+        discover_source_roots(Path::new(GRADLE_PROJECT)).unwrap(),
+        vec![
+            source_root(
+                "tests/resources_gradle_modules/src/main/java",
+                "resources_gradle_modules"
+            ),
+            source_root("tests/resources_gradle_modules/api/src/main/kotlin", "api"),
+            source_root(
+                "tests/resources_gradle_modules/common/core/src/main/java",
+                "common:core"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_discover_maven_modules() {
+    // コメントアウトされたモジュールは含めない
+    assert!(is_project_root(Path::new(MAVEN_PROJECT)));
+    assert_eq!(
+        discover_source_roots(Path::new(MAVEN_PROJECT)).unwrap(),
+        vec![
+            source_root("tests/resources_maven_modules/web/src/main/java", "web"),
+            source_root(
+                "tests/resources_maven_modules/web/admin/src/main/java",
+                "web/admin"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_not_a_project() {
+    assert!(!is_project_root(Path::new("tests/resources")));
+    assert!(discover_source_roots(Path::new("tests/resources"))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_endpoints_are_tagged_with_module() {
+    // 親クラスは別のモジュールから見つけ、継承したエンドポイントは子クラスのモジュールになる
+    // テストのソース（src/test）はスキャンしない
+    let roots = discover_source_roots(Path::new(GRADLE_PROJECT)).unwrap();
+    assert_eq!(
+        endpoint_modules(&roots),
+        vec![
+            endpoint_module("/", "resources_gradle_modules"),
+            endpoint_module("/orders", "api"),
+            endpoint_module("/orders/ping", "api"),
+            endpoint_module("/health", "common:core"),
+        ]
+    );
+
+    let roots = discover_source_roots(Path::new(MAVEN_PROJECT)).unwrap();
+    assert_eq!(
+        endpoint_modules(&roots),
+        vec![
+            endpoint_module("/web", "web"),
+            endpoint_module("/admin", "web/admin"),
+        ]
+    );
+}
+
+#[test]
+fn test_multiple_roots_without_modules() {
+    // モジュールの分からないディレクトリのエンドポイントにはモジュールを設定しない
+    let roots = vec![
+        SourceRoot::new("tests/resources_gradle_modules/api/src/main/kotlin"),
+        SourceRoot::new("tests/resources_gradle_modules/common/core/src/main/java"),
+    ];
+    assert_eq!(
+        endpoint_modules(&roots),
+        vec![
+            ("/orders".to_string(), None),
+            ("/orders/ping".to_string(), None),
+            ("/health".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn test_groovy_settings() {
+    let dir = std::env::temp_dir().join(format!("path-finder-project-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    for module in ["api", "web/admin", "included-build"] {
+        std::fs::create_dir_all(dir.join(module).join("src/main/java")).unwrap();
+    }
+    std::fs::write(
+        dir.join("settings.gradle"),
+        "rootProject.name = 'shop'\n// include ':commented'\ninclude ':api',\n        ':web:admin'\nincludeBuild 'included-build'\n",
+    )
+    .unwrap();
+
+    let modules: Vec<Option<String>> = discover_source_roots(&dir)
+        .unwrap()
+        .into_iter()
+        .map(|root| root.module)
+        .collect();
+    assert_eq!(
+        modules,
+        vec![Some("api".to_string()), Some("web:admin".to_string())]
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,12 @@
+package com.example.orders
+
+import com.acme.web.GetMapping
+import com.acme.web.RequestMapping
+
+// Spring以外のアノテーションだけを持つクラスはコントローラーではない
+@RequestMapping("/client")
+class AcmeClient {
+
+    @GetMapping("/fetch")
+    fun fetch(): String = ""
+}
@@ -0,0 +1,26 @@
+package com.example.orders;
+
+import com.acme.web.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// Springと同名でも、Spring以外のパッケージのアノテーションはマッピングとして扱わない
+@RestController
+@RequestMapping("/acme")
+public class AcmeController {
+
+    @GetMapping("/imported")
+    public String imported() {
+        return "";
+    }
+
+    @com.acme.web.PostMapping("/qualified")
+    public String qualified() {
+        return "";
+    }
+
+    @org.springframework.web.bind.annotation.PutMapping("/spring")
+    public String spring() {
+        return "";
+    }
+}
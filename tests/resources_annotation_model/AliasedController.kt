@@ -0,0 +1,14 @@
+package com.example.orders
+
+import org.springframework.web.bind.annotation.GetMapping as Get
+import org.springframework.web.bind.annotation.RequestMapping as Route
+import org.springframework.web.bind.annotation.RestController as Api
+
+// Springのアノテーションをimportの別名で参照している
+@Api
+@Route("/aliased")
+class AliasedController {
+
+    @Get("/alias")
+    fun alias(): String = ""
+}
@@ -0,0 +1,15 @@
+package com.example.invoices
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping(path = ["/invoices"], headers = arrayOf("X-Api-Version=2"))
+class InvoiceController {
+
+    @GetMapping("/search", "/find", params = arrayOf("q", "status!=closed"))
+    fun search(@RequestParam("q") query: String, @RequestParam(required = false) limit: Int?): String = ""
+
+    @org.springframework.web.bind.annotation.DeleteMapping(path = ["/{id}"], headers = ["X-Confirm"])
+    fun cancel(@PathVariable("id") id: Long) {
+    }
+}
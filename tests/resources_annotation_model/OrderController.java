@@ -0,0 +1,17 @@
+package com.example.orders;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping(path = "/orders", headers = "X-Api-Version=2")
+public class OrderController {
+
+    @GetMapping(value = {"/search", "/find"}, params = {"q", "status!=closed"})
+    public String search(@RequestParam("q") String query, @RequestParam(required = false) Integer limit) {
+        return "";
+    }
+
+    @org.springframework.web.bind.annotation.DeleteMapping(path = {"/{id}"}, headers = {"X-Confirm"})
+    public void cancel(@PathVariable("id") long id) {
+    }
+}
@@ -0,0 +1,4 @@
+package com.example.index;
+
+public abstract class AbstractHandler<T> {
+}
@@ -0,0 +1,12 @@
+package com.example.index;
+
+import java.io.Serializable;
+
+public class Handlers {
+
+    public static class ReadHandler extends AbstractHandler<String> implements Serializable {
+    }
+
+    interface Auditing extends com.example.index.Versioned {
+    }
+}
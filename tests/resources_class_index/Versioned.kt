@@ -0,0 +1,5 @@
+package com.example.index
+
+interface Versioned
+
+open class KotlinHandler : AbstractHandler<Int>(), Versioned
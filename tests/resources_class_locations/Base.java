@@ -0,0 +1,10 @@
+package com.example.locations;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class Base {
+    @GetMapping("/top-level-base")
+    public String topLevel() {
+        return "top-level";
+    }
+}
@@ -0,0 +1,14 @@
+package com.example.locations
+
+import org.springframework.web.bind.annotation.GetMapping
+
+// 1つのファイルに複数のクラスを宣言するケース
+abstract class KotlinBase {
+    @GetMapping("/kotlin-base")
+    fun kotlinBase(): String = "base"
+}
+
+interface KotlinApi {
+    @GetMapping("/kotlin-api")
+    fun kotlinApi(): String
+}
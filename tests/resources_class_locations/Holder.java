@@ -0,0 +1,21 @@
+package com.example.locations;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+public class Holder {
+
+    public abstract static class Base {
+        @GetMapping("/sibling")
+        public String sibling() {
+            return "sibling";
+        }
+    }
+
+    // 外側のクラスのメンバーであるBaseは、同じパッケージのBaseより優先される
+    @RestController
+    @RequestMapping("/holder")
+    public static class Api extends Base {
+    }
+}
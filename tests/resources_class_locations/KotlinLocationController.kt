@@ -0,0 +1,10 @@
+package com.example.locations
+
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/kotlin-multi")
+class KotlinLocationController : KotlinBase(), KotlinApi {
+    override fun kotlinApi(): String = "api"
+}
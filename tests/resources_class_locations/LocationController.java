@@ -0,0 +1,9 @@
+package com.example.locations;
+
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+@RequestMapping("/hidden-parent")
+public class LocationController extends HiddenBase {
+}
@@ -0,0 +1,21 @@
+package com.example.locations;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public class Support {
+
+    public static class Inner {
+        @GetMapping("/inner")
+        public String inner() {
+            return "inner";
+        }
+    }
+}
+
+// ファイル名と異なるパッケージプライベートなトップレベルクラス
+class HiddenBase {
+    @GetMapping("/hidden")
+    public String hidden() {
+        return "hidden";
+    }
+}
@@ -0,0 +1,9 @@
+package com.example.other
+
+import com.example.locations.Support
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/inner-kotlin")
+class KotlinInnerController : Support.Inner()
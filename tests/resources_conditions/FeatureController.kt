@@ -0,0 +1,13 @@
+package com.example.conditions
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping("/features")
+class FeatureController {
+
+    @GetMapping(headers = ["X-Tenant"], params = ["!preview", "flag=on"])
+    fun listFeatures(): String {
+        return "features"
+    }
+}
@@ -0,0 +1,14 @@
+package com.example.conditions;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping("/unresolved")
+public class UnresolvedConditionController {
+
+    // 解決できない定数は条件・メディアタイプとして出力せず、診断結果として報告する
+    @GetMapping(headers = {"X-Known", MissingHeaders.NAME}, produces = MissingTypes.JSON)
+    public String missing() {
+        return "missing";
+    }
+}
@@ -0,0 +1,19 @@
+package com.example.conditions;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping(value = "/versioned", headers = "X-API-Version=2")
+public class VersionedController {
+    private static final String DEBUG_PARAM = "!debug";
+
+    @GetMapping(params = {"mode!=legacy", DEBUG_PARAM})
+    public String list() {
+        return "list";
+    }
+
+    @PostMapping(headers = {"X-Request-Id", "Content-Encoding!=gzip"})
+    public String create() {
+        return "created";
+    }
+}
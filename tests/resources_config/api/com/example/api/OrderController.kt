@@ -0,0 +1,14 @@
+package com.example.api
+
+import com.example.annotation.ApiController
+import com.example.annotation.ApiGet
+import com.example.support.ControllerSupport
+import org.springframework.web.bind.annotation.RequestMapping
+
+@ApiController
+@RequestMapping("/orders")
+class OrderController : ControllerSupport() {
+
+    @ApiGet("/{id}")
+    fun getOrder(id: Long): String = id.toString()
+}
@@ -0,0 +1,29 @@
+package com.example.api;
+
+import com.example.annotation.ApiController;
+import com.example.annotation.ApiGet;
+import com.example.annotation.ApiPost;
+import com.example.common.BaseApiController;
+import org.springframework.web.bind.annotation.PathVariable;
+import org.springframework.web.bind.annotation.RequestMapping;
+
+@ApiController
+@RequestMapping("/users")
+public class UserController extends BaseApiController {
+
+    @ApiGet("/{id}")
+    public String getUser(@PathVariable String id) {
+        return id;
+    }
+
+    @ApiPost
+    public String createUser() {
+        return "created";
+    }
+
+    // 別名ではないアノテーションは無視する
+    @Deprecated
+    public String legacy() {
+        return "legacy";
+    }
+}
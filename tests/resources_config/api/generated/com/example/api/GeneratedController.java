@@ -0,0 +1,13 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class GeneratedController {
+
+    @GetMapping("/generated")
+    public String generated() {
+        return "generated";
+    }
+}
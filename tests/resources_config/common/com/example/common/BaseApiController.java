@@ -0,0 +1,11 @@
+package com.example.common;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class BaseApiController {
+
+    @GetMapping("/health")
+    public String health() {
+        return "ok";
+    }
+}
@@ -0,0 +1,14 @@
+package com.example.scripts;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// rootsに含まれないため、設定ファイルのディレクトリを指定してもスキャンされない
+@RestController
+public class ScriptController {
+
+    @GetMapping("/script")
+    public String script() {
+        return "script";
+    }
+}
@@ -0,0 +1,8 @@
+package com.example.first;
+
+public final class Paths {
+    public static final String ROOT = "/first";
+
+    private Paths() {
+    }
+}
@@ -0,0 +1,3 @@
+package com.example.kotlin
+
+const val KOTLIN_ROOT = "/kotlin"
@@ -0,0 +1,9 @@
+package com.example.second;
+
+public final class Paths {
+    public static final String ROOT = "/second";
+    public static final String QUOTED = "/say\"hi\"/caf\u00e9";
+
+    private Paths() {
+    }
+}
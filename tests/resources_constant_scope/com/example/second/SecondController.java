@@ -0,0 +1,17 @@
+package com.example.second;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+public class SecondController {
+    // 同じパッケージのPaths（com.example.first.Pathsではない）
+    @GetMapping(Paths.ROOT)
+    public String samePackage() {
+        return "second";
+    }
+
+    @GetMapping(Paths.QUOTED)
+    public String escaped() {
+        return "escaped";
+    }
+}
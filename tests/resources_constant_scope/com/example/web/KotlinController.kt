@@ -0,0 +1,14 @@
+package com.example.web
+
+import com.example.kotlin.KOTLIN_ROOT as ROOT_ALIAS
+import org.springframework.web.bind.annotation.*
+
+@RestController
+class KotlinController {
+    @GetMapping("$ROOT_ALIAS/alias")
+    fun alias(): String = "alias"
+
+    // importしていない別パッケージのトップレベル定数は参照できない
+    @GetMapping(KOTLIN_ROOT)
+    fun notImported(): String = "not imported"
+}
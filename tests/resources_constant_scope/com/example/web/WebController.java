@@ -0,0 +1,19 @@
+package com.example.web;
+
+import static com.example.first.Paths.ROOT;
+
+import com.example.second.Paths;
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+public class WebController {
+    @GetMapping(Paths.ROOT + "/imported")
+    public String imported() {
+        return "imported";
+    }
+
+    @GetMapping(ROOT + "/static")
+    public String staticImport() {
+        return "static";
+    }
+}
@@ -0,0 +1,14 @@
+package com.example.web;
+
+import com.example.first.*;
+import com.example.second.*;
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+public class WildcardController {
+    // 2つのワイルドカードimportのどちらのPathsか決められない
+    @GetMapping(Paths.ROOT)
+    public String ambiguous() {
+        return "ambiguous";
+    }
+}
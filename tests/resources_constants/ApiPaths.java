@@ -0,0 +1,14 @@
+package com.example.constants;
+
+public final class ApiPaths {
+    public static final String API = "/api";
+    public static final String BASE = API + "/users";
+    public static final String BY_ID = "/{id}";
+
+    private ApiPaths() {
+    }
+
+    public static final class Admin {
+        public static final String ROOT = "/admin";
+    }
+}
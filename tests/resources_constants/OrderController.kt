@@ -0,0 +1,27 @@
+package com.example.constants
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping(OrderPaths.BASE)
+class OrderController {
+
+    @GetMapping(OrderPaths.BY_ID)
+    fun getOrder(@PathVariable id: Long): String {
+        return "order"
+    }
+
+    @PostMapping(value = [ITEMS])
+    fun addItem(@RequestBody item: String): String {
+        return "added"
+    }
+
+    @GetMapping("${ITEMS}/{itemId}")
+    fun getItem(@PathVariable itemId: Long): String {
+        return "item"
+    }
+
+    companion object {
+        const val ITEMS = "/items"
+    }
+}
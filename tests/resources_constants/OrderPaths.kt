@@ -0,0 +1,8 @@
+package com.example.constants
+
+const val ORDERS = "/orders"
+
+object OrderPaths {
+    const val BASE = "/api$ORDERS"
+    const val BY_ID = "/{id}"
+}
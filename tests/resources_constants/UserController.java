@@ -0,0 +1,29 @@
+package com.example.constants;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping(ApiPaths.BASE + "/v2")
+public class UserController {
+    private static final String SEARCH = "/search";
+
+    @GetMapping(ApiPaths.BY_ID)
+    public String getUser(@PathVariable Long id) {
+        return "user";
+    }
+
+    @GetMapping(value = SEARCH)
+    public String searchUsers(@RequestParam String q) {
+        return "users";
+    }
+
+    @PostMapping(ApiPaths.Admin.ROOT + ApiPaths.BY_ID)
+    public String adminUpdate(@PathVariable Long id) {
+        return "updated";
+    }
+
+    @DeleteMapping(UndefinedPaths.REMOVED)
+    public String deleteUser() {
+        return "deleted";
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.controller;
+
+import org.springframework.web.bind.annotation.*;
+
+// 親クラス - アノテーションなし、OrderControllerから継承される
+public class AuditedController {
+
+    @GetMapping("/audit")
+    public String audit() {
+        return "audit";
+    }
+}
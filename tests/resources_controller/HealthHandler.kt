@@ -0,0 +1,12 @@
+package com.example.controller
+
+import org.springframework.web.bind.annotation.*
+
+// クラスレベルのアノテーションなし、メソッドレベルのマッピングのみ
+class HealthHandler {
+
+    @GetMapping("/health")
+    fun health(): String {
+        return "ok"
+    }
+}
@@ -0,0 +1,19 @@
+package com.example.controller
+
+import org.springframework.stereotype.Controller
+import org.springframework.web.bind.annotation.*
+
+// クラスレベルの@RequestMappingなし、@Controllerのみ
+@Controller
+class InvoiceController {
+
+    @GetMapping("/invoices")
+    fun listInvoices(): String {
+        return "invoices"
+    }
+
+    @PostMapping("/invoices")
+    fun createInvoice(@RequestBody invoice: Invoice): String {
+        return "created"
+    }
+}
@@ -0,0 +1,18 @@
+package com.example.controller;
+
+import org.springframework.web.bind.annotation.*;
+
+// クラスレベルの@RequestMappingなし、@RestControllerのみ
+@RestController
+public class OrderController extends AuditedController {
+
+    @GetMapping("/orders")
+    public String listOrders() {
+        return "orders";
+    }
+
+    @RequestMapping(value = "/orders/{id}", method = RequestMethod.DELETE)
+    public String deleteOrder(@PathVariable Long id) {
+        return "deleted";
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.controller;
+
+import org.springframework.web.bind.annotation.*;
+
+// クラスレベルのアノテーションなし、メソッドレベルのマッピングのみ
+public class PingHandler {
+
+    @GetMapping("/ping")
+    public String ping() {
+        return "pong";
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.x;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+// XControllerに継承されている親クラス
+public abstract class BaseController {
+    @GetMapping("/x-base")
+    public String base() {
+        return "base";
+    }
+}
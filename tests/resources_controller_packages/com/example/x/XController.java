@@ -0,0 +1,9 @@
+package com.example.x;
+
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+@RequestMapping("/x")
+public class XController extends BaseController {
+}
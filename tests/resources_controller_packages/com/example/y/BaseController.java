@@ -0,0 +1,11 @@
+package com.example.y;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+// 別パッケージの同名クラスは継承されているが、このクラスはどこからも継承されていない
+public class BaseController {
+    @GetMapping("/y-standalone")
+    public String standalone() {
+        return "standalone";
+    }
+}
@@ -0,0 +1,11 @@
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class NoPackageController {
+
+    @GetMapping("/no-package")
+    public String get() {
+        return "ok";
+    }
+}
@@ -0,0 +1,16 @@
+package com.example.orders;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+public class AdminControllers {
+
+    @RestController
+    public static class OrderAdminController {
+
+        @GetMapping("/admin/orders")
+        public String listOrders() {
+            return "orders";
+        }
+    }
+}
@@ -0,0 +1,16 @@
+package com.example.orders;
+
+import com.example.shared.BaseController;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+@RequestMapping("/orders")
+public class OrderController extends BaseController {
+
+    @GetMapping
+    public String listOrders() {
+        return "orders";
+    }
+}
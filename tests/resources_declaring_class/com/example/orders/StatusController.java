@@ -0,0 +1,13 @@
+package com.example.orders;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class StatusController {
+
+    @GetMapping("/orders/status")
+    public String status() {
+        return "ok";
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.shared;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class BaseController {
+
+    @GetMapping("/version")
+    public String version() {
+        return "1";
+    }
+}
@@ -0,0 +1,9 @@
+package com.example.shared
+
+import org.springframework.web.bind.annotation.GetMapping
+
+interface PingApi {
+
+    @GetMapping("/ping")
+    fun ping(): String
+}
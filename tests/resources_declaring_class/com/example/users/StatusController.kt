@@ -0,0 +1,16 @@
+package com.example.users
+
+import com.example.shared.PingApi
+import org.springframework.web.bind.annotation.GetMapping
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/users")
+class StatusController : PingApi {
+
+    @GetMapping("/status")
+    fun status(): String = "ok"
+
+    override fun ping(): String = "pong"
+}
@@ -0,0 +1,17 @@
+package com.example.diagnostics;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping("/broken")
+public class BrokenAnnotationController {
+    @GetMapping("/ok")
+    public String ok() {
+        return "ok";
+    }
+
+    @GetMapping(value = "/missing-paren"
+    public String missingParen() {
+        return "broken";
+    }
+}
@@ -0,0 +1,8 @@
+package com.example.diagnostics;
+
+// caf
+@RestController
+public class Latin1Controller {
+    @GetMapping("/latin1")
+    public String get() { return "x"; }
+}
@@ -0,0 +1,10 @@
+package com.example.diagnostics
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping("/orders")
+class OrderController : MissingBaseController() {
+    @GetMapping("/{id}")
+    fun getOrder(@PathVariable id: Long): String = "order"
+}
@@ -0,0 +1,11 @@
+package com.example;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class StatusEndpoints {
+
+    @GetMapping("/status")
+    public String status() {
+        return "ok";
+    }
+}
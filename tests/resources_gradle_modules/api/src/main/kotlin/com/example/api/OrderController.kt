@@ -0,0 +1,14 @@
+package com.example.api
+
+import com.example.core.BaseController
+import org.springframework.web.bind.annotation.GetMapping
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/orders")
+class OrderController : BaseController() {
+
+    @GetMapping
+    fun listOrders(): List<String> = emptyList()
+}
@@ -0,0 +1,14 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// テストのソースはスキャンしない
+@RestController
+public class TestOnlyController {
+
+    @GetMapping("/test-only")
+    public String testOnly() {
+        return "test";
+    }
+}
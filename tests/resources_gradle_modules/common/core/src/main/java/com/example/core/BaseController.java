@@ -0,0 +1,11 @@
+package com.example.core;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class BaseController {
+
+    @GetMapping("/ping")
+    public String ping() {
+        return "pong";
+    }
+}
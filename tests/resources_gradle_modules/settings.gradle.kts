@@ -0,0 +1,6 @@
+rootProject.name = "shop"
+
+include(":api")
+include(
+    ":common:core", // 共通モジュール
+)
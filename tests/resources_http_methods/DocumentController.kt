@@ -0,0 +1,18 @@
+package com.example.methods
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping("/documents")
+class DocumentController {
+
+    @RequestMapping(value = ["/{id}"], method = [RequestMethod.GET, RequestMethod.HEAD])
+    fun getDocument(@PathVariable id: Long): String {
+        return "document"
+    }
+
+    @RequestMapping(method = [RequestMethod.PUT, RequestMethod.PATCH])
+    fun updateDocuments(): String {
+        return "updated"
+    }
+}
@@ -0,0 +1,36 @@
+package com.example.methods;
+
+import org.springframework.web.bind.annotation.*;
+
+import static org.springframework.web.bind.annotation.RequestMethod.OPTIONS;
+
+@RestController
+@RequestMapping("/resources")
+public class ResourceController {
+
+    @RequestMapping(value = "/{id}", method = {RequestMethod.GET, RequestMethod.HEAD})
+    public String getResource(@PathVariable Long id) {
+        return "resource";
+    }
+
+    @RequestMapping(method = OPTIONS)
+    public String options() {
+        return "options";
+    }
+
+    @RequestMapping(value = "/trace", method = RequestMethod.TRACE)
+    public String trace() {
+        return "trace";
+    }
+
+    // RequestMethodの定数以外は無視して報告する
+    @RequestMapping(value = "/custom", method = {RequestMethod.GET, HttpMethods.CUSTOM})
+    public String custom() {
+        return "custom";
+    }
+
+    @RequestMapping("/any")
+    public String any() {
+        return "any";
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.admin;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class BaseController {
+
+    @GetMapping("/admin-health")
+    public String health() {
+        return "admin";
+    }
+}
@@ -0,0 +1,10 @@
+package com.example.kotlin
+
+import com.example.shop.BaseController as ShopBase
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+// Kotlinのimportの別名
+@RestController
+@RequestMapping("/alias")
+class AliasController : ShopBase()
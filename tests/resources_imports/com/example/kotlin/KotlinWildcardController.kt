@@ -0,0 +1,9 @@
+package com.example.kotlin
+
+import com.example.admin.*
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/kotlin-wildcard")
+class KotlinWildcardController : BaseController()
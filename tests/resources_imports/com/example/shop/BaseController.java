@@ -0,0 +1,11 @@
+package com.example.shop;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public abstract class BaseController {
+
+    @GetMapping("/shop-health")
+    public String health() {
+        return "shop";
+    }
+}
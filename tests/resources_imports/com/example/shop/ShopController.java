@@ -0,0 +1,16 @@
+package com.example.shop;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// 同じパッケージのBaseControllerを継承
+@RestController
+@RequestMapping("/same-package")
+public class ShopController extends BaseController {
+
+    @GetMapping("/items")
+    public String items() {
+        return "items";
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.web;
+
+import com.example.admin.BaseController;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// 単一型のimport
+@RestController
+@RequestMapping("/single-import")
+public class AdminController extends BaseController {
+}
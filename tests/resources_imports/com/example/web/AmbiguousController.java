@@ -0,0 +1,18 @@
+package com.example.web;
+
+import com.example.admin.*;
+import com.example.shop.*;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// どちらのBaseControllerか決められない
+@RestController
+@RequestMapping("/ambiguous")
+public class AmbiguousController extends BaseController {
+
+    @GetMapping("/own")
+    public String own() {
+        return "own";
+    }
+}
@@ -0,0 +1,10 @@
+package com.example.web;
+
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// 完全修飾名での継承
+@RestController
+@RequestMapping("/qualified")
+public class QualifiedController extends com.example.admin.BaseController {
+}
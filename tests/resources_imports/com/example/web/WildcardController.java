@@ -0,0 +1,11 @@
+package com.example.web;
+
+import com.example.shop.*;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// ワイルドカードのimport
+@RestController
+@RequestMapping("/wildcard-import")
+public class WildcardController extends BaseController {
+}
@@ -0,0 +1,9 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.*;
+
+public interface AdminApi {
+
+    @DeleteMapping("/pets/{id}")
+    String deletePet(@PathVariable("id") Long id);
+}
@@ -0,0 +1,9 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.*;
+
+public interface BaseApi {
+
+    @GetMapping("/version")
+    String version();
+}
@@ -0,0 +1,9 @@
+package com.example.api
+
+import org.springframework.web.bind.annotation.*
+
+interface InventoryApi {
+
+    @GetMapping("/inventory")
+    fun inventory(): String
+}
@@ -0,0 +1,13 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.*;
+
+@RequestMapping("/api/orders")
+public interface OrderApi {
+
+    @GetMapping("/{id}")
+    String getOrder(@PathVariable("id") Long id);
+
+    @GetMapping
+    String listOrders();
+}
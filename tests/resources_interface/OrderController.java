@@ -0,0 +1,20 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.*;
+
+// クラスレベルの@RequestMappingはインターフェースのものを使う
+@RestController
+public class OrderController implements OrderApi {
+
+    @Override
+    public String getOrder(Long id) {
+        return "order";
+    }
+
+    // インターフェースのマッピングを上書きしている
+    @Override
+    @GetMapping("/all")
+    public String listOrders() {
+        return "orders";
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.*;
+
+public interface PetApi extends BaseApi {
+
+    @GetMapping("/pets")
+    String listPets();
+
+    @GetMapping("/pets/{id}")
+    String getPet(@PathVariable("id") Long id);
+}
@@ -0,0 +1,28 @@
+package com.example.api;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping("/v1")
+public class PetController implements PetApi, AdminApi {
+
+    @Override
+    public String version() {
+        return "1.0";
+    }
+
+    @Override
+    public String listPets() {
+        return "pets";
+    }
+
+    @Override
+    public String getPet(Long id) {
+        return "pet";
+    }
+
+    @Override
+    public String deletePet(Long id) {
+        return "deleted";
+    }
+}
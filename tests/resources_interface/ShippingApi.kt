@@ -0,0 +1,10 @@
+package com.example.api
+
+import org.springframework.web.bind.annotation.*
+
+@RequestMapping("/shipping")
+interface ShippingApi {
+
+    @GetMapping("/rates")
+    fun rates(): String
+}
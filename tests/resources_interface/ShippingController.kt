@@ -0,0 +1,9 @@
+package com.example.api
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+class ShippingController : ShippingApi {
+
+    override fun rates(): String = "rates"
+}
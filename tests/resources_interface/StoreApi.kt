@@ -0,0 +1,9 @@
+package com.example.api
+
+import org.springframework.web.bind.annotation.*
+
+interface StoreApi : InventoryApi {
+
+    @PostMapping("/orders")
+    fun placeOrder(@RequestBody order: String): String
+}
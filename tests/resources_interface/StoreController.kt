@@ -0,0 +1,12 @@
+package com.example.api
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping("/store")
+class StoreController : StoreApi, java.io.Serializable {
+
+    override fun inventory(): String = "inventory"
+
+    override fun placeOrder(order: String): String = "placed"
+}
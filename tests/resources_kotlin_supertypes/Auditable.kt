@@ -0,0 +1,8 @@
+package com.example.supertypes
+
+import org.springframework.web.bind.annotation.GetMapping
+
+interface Auditable {
+    @GetMapping("/audit")
+    fun audit(): List<String>
+}
@@ -0,0 +1,11 @@
+package com.example.supertypes
+
+import org.springframework.web.bind.annotation.GetMapping
+import org.springframework.web.bind.annotation.PathVariable
+
+abstract class GenericBaseController<T> {
+    @GetMapping("/{id}")
+    fun findById(@PathVariable id: Long): T? {
+        return null
+    }
+}
@@ -0,0 +1,16 @@
+package com.example.supertypes
+
+import org.springframework.web.bind.annotation.GetMapping
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/labels")
+class LabelController {
+    private val label : String = "labels"
+
+    @GetMapping
+    fun label(): String {
+        return label
+    }
+}
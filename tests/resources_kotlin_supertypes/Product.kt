@@ -0,0 +1,3 @@
+package com.example.supertypes
+
+data class Product(val id: Long, val name: String)
@@ -0,0 +1,30 @@
+package com.example.supertypes
+
+import org.springframework.web.bind.annotation.GetMapping
+import org.springframework.web.bind.annotation.RequestMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+@RequestMapping("/products")
+class ProductController(
+    private val auditLog: List<String>
+)
+    : GenericBaseController<Product>(),
+      Auditable,
+      com.example.supertypes.Trackable<Product> {
+
+    private val label : String = "products"
+
+    @GetMapping
+    fun list(): List<Product> {
+        return emptyList()
+    }
+
+    override fun audit(): List<String> {
+        return auditLog
+    }
+
+    override fun tracking(): List<Product> {
+        return emptyList()
+    }
+}
@@ -0,0 +1,8 @@
+package com.example.supertypes
+
+import org.springframework.web.bind.annotation.GetMapping
+
+interface Trackable<T> {
+    @GetMapping("/tracking")
+    fun tracking(): List<T>
+}
@@ -0,0 +1,13 @@
+package com.example.legacy;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class LegacyController {
+
+    @GetMapping("/legacy")
+    public String get() {
+        return "/legacy";
+    }
+}
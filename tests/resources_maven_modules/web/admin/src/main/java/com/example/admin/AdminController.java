@@ -0,0 +1,13 @@
+package com.example.admin;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class AdminController {
+
+    @GetMapping("/admin")
+    public String get() {
+        return "/admin";
+    }
+}
@@ -0,0 +1,13 @@
+package com.example.web;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class WebController {
+
+    @GetMapping("/web")
+    public String get() {
+        return "/web";
+    }
+}
@@ -0,0 +1,27 @@
+package com.example.media;
+
+import org.springframework.http.MediaType;
+import org.springframework.web.bind.annotation.*;
+
+import static org.springframework.http.MediaType.TEXT_PLAIN_VALUE;
+
+@RestController
+@RequestMapping(value = "/reports", produces = MediaType.APPLICATION_JSON_VALUE)
+public class ReportController {
+    private static final String CSV = "text/csv";
+
+    @GetMapping("/{id}")
+    public String getReport(@PathVariable Long id) {
+        return "report";
+    }
+
+    @PostMapping(consumes = {MediaType.APPLICATION_JSON_VALUE, MediaType.APPLICATION_XML_VALUE})
+    public String createReport(@RequestBody String report) {
+        return "created";
+    }
+
+    @GetMapping(value = "/{id}/export", produces = {CSV, TEXT_PLAIN_VALUE})
+    public String exportReport(@PathVariable Long id) {
+        return "id,name";
+    }
+}
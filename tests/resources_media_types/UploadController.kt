@@ -0,0 +1,19 @@
+package com.example.media
+
+import org.springframework.http.MediaType
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping("/uploads")
+class UploadController {
+
+    @PostMapping(consumes = [MediaType.MULTIPART_FORM_DATA_VALUE], produces = ["application/json"])
+    fun upload(@RequestPart file: String): String {
+        return "uploaded"
+    }
+
+    @GetMapping("/{id}")
+    fun getUpload(@PathVariable id: Long): String {
+        return "upload"
+    }
+}
@@ -0,0 +1,13 @@
+package com.example.web;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+import org.springframework.web.servlet.support.WebContentGenerator;
+
+@RestController
+public class FrameworkController extends WebContentGenerator {
+    @GetMapping("/framework")
+    public String framework() {
+        return "framework";
+    }
+}
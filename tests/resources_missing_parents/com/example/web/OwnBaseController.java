@@ -0,0 +1,12 @@
+package com.example.web;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class OwnBaseController extends BaseController {
+    @GetMapping("/own")
+    public String own() {
+        return "own";
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.web
+
+import com.example.platform.PlatformResource
+import org.springframework.web.bind.annotation.GetMapping
+import org.springframework.web.bind.annotation.RestController
+
+@RestController
+class PlatformController : PlatformResource() {
+    @GetMapping("/platform")
+    fun platform(): String = "platform"
+}
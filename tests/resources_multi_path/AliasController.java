@@ -0,0 +1,12 @@
+package com.example.multipath;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+public class AliasController {
+
+    @RequestMapping(value = {"/status", "/health"}, method = RequestMethod.GET)
+    public String status() {
+        return "ok";
+    }
+}
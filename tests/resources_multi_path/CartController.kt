@@ -0,0 +1,18 @@
+package com.example.multipath
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping(value = ["/cart", "/basket"])
+class CartController {
+
+    @GetMapping(value = ["/lines", "/entries"])
+    fun listLines(): String {
+        return "lines"
+    }
+
+    @DeleteMapping("/lines/{id}")
+    fun deleteLine(@PathVariable id: Long): String {
+        return "deleted"
+    }
+}
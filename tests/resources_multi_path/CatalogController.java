@@ -0,0 +1,23 @@
+package com.example.multipath;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping({"/catalog", "/shop"})
+public class CatalogController extends PagingSupport {
+
+    @GetMapping({"/items", "/products"})
+    public String listItems() {
+        return "items";
+    }
+
+    @PostMapping("/items")
+    public String createItem(@RequestBody String item) {
+        return "created";
+    }
+
+    @GetMapping
+    public String index() {
+        return "index";
+    }
+}
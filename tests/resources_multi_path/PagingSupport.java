@@ -0,0 +1,11 @@
+package com.example.multipath;
+
+import org.springframework.web.bind.annotation.*;
+
+public abstract class PagingSupport {
+
+    @GetMapping("/page")
+    public String page(@RequestParam int number) {
+        return "page";
+    }
+}
@@ -0,0 +1,18 @@
+package com.example.alias
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping(path = ["/carriers"])
+class CarrierController {
+
+    @GetMapping(path = ["/{code}"])
+    fun getCarrier(@PathVariable code: String): String {
+        return "carrier"
+    }
+
+    @RequestMapping(method = [RequestMethod.DELETE], path = ["/{code}", "/by-code/{code}"])
+    fun deleteCarrier(@PathVariable code: String): String {
+        return "deleted"
+    }
+}
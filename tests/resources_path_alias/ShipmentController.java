@@ -0,0 +1,23 @@
+package com.example.alias;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping(path = "/shipments")
+public class ShipmentController {
+
+    @GetMapping(path = "/{id}")
+    public String getShipment(@PathVariable Long id) {
+        return "shipment";
+    }
+
+    @RequestMapping(path = {"/track", "/trace"}, method = RequestMethod.GET)
+    public String track() {
+        return "tracked";
+    }
+
+    @PostMapping(consumes = "application/json", path = "/bulk")
+    public String bulkCreate(@RequestBody String body) {
+        return "created";
+    }
+}
@@ -0,0 +1,17 @@
+package com.example.normalize;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+public class EmptyPathController {
+
+    @GetMapping("")
+    public String home() {
+        return "home";
+    }
+
+    @PostMapping(path = {})
+    public String submit() {
+        return "submit";
+    }
+}
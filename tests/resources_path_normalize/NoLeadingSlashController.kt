@@ -0,0 +1,13 @@
+package com.example.normalize
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+@RequestMapping("reports")
+class NoLeadingSlashController {
+
+    @GetMapping("daily")
+    fun daily(): String {
+        return "daily"
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.normalize
+
+import org.springframework.web.bind.annotation.*
+
+@RestController
+class RootController {
+
+    @GetMapping
+    fun index(): String {
+        return "index"
+    }
+}
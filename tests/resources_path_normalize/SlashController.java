@@ -0,0 +1,28 @@
+package com.example.normalize;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping("/api/")
+public class SlashController {
+
+    @GetMapping("items")
+    public String listItems() {
+        return "items";
+    }
+
+    @GetMapping("/items/{id}/")
+    public String getItem(@PathVariable Long id) {
+        return "item";
+    }
+
+    @PostMapping("//bulk")
+    public String bulk() {
+        return "bulk";
+    }
+
+    @GetMapping
+    public String root() {
+        return "root";
+    }
+}
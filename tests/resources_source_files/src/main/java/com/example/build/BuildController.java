@@ -0,0 +1,13 @@
+package com.example.build;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class BuildController {
+
+    @GetMapping("/builds")
+    public String get() {
+        return "/builds";
+    }
+}
@@ -0,0 +1,13 @@
+package com.example.ignored;
+
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+@RestController
+public class IgnoredController {
+
+    @GetMapping("/ignored")
+    public String get() {
+        return "/ignored";
+    }
+}
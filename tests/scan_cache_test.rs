@@ -0,0 +1,274 @@
+use path_finder::{scan_directory_with_options, Endpoint, ScanOptions};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// テストごとに作成し、終了時に削除する作業ディレクトリ
+struct Workspace {
+    root: PathBuf,
+}
+
+impl Workspace {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!(
+            "path-finder-cache-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Workspace { root }
+    }
+
+    fn source_dir(&self) -> String {
+        self.root.join("src").to_string_lossy().to_string()
+    }
+
+    fn cache_dir(&self) -> PathBuf {
+        self.root.join(".path-finder/cache")
+    }
+
+    fn write(&self, relative_path: &str, content: &str) {
+        let path = self.root.join("src").join(relative_path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn remove(&self, relative_path: &str) {
+        fs::remove_file(self.root.join("src").join(relative_path)).unwrap();
+    }
+
+    fn scan(&self) -> Vec<Endpoint> {
+        scan_directory_with_options(
+            &self.source_dir(),
+            &ScanOptions {
+                cache_dir: Some(self.cache_dir()),
+                ..ScanOptions::default()
+            },
+        )
+        .unwrap()
+    }
+
+    fn read_cache(&self) -> serde_json::Value {
+        let content = fs::read(self.cache_dir().join("scan-cache.json")).unwrap();
+        serde_json::from_slice(&content).unwrap()
+    }
+
+    fn write_cache(&self, cache: &serde_json::Value) {
+        fs::write(
+            self.cache_dir().join("scan-cache.json"),
+            serde_json::to_vec(cache).unwrap(),
+        )
+        .unwrap();
+    }
+
+    fn cache_key(&self, relative_path: &str) -> String {
+        Path::new(&self.source_dir())
+            .join(relative_path)
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn summary(endpoints: &[Endpoint]) -> Vec<String> {
+    endpoints
+        .iter()
+        .map(|endpoint| {
+            format!(
+                "{} {} {}#{}",
+                endpoint.http_method, endpoint.path, endpoint.class_name, endpoint.method_name
+            )
+        })
+        .collect()
+}
+
+const BASE_CONTROLLER: &str = r#"
+package com.example.base;
+
+import org.springframework.web.bind.annotation.GetMapping;
+
+public class BaseController {
+    @GetMapping("/health")
+    public String health() {
+        return "ok";
+    }
+}
+"#;
+
+const USER_CONTROLLER: &str = r#"
+package com.example.web;
+
+import com.example.base.BaseController;
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping(ApiPaths.USERS)
+public class UserController extends BaseController {
+    @GetMapping("/{id}")
+    public String getUser() {
+        return "user";
+    }
+}
+"#;
+
+const API_PATHS: &str = r#"
+package com.example.web;
+
+public class ApiPaths {
+    public static final String USERS = "/users";
+}
+"#;
+
+fn create_workspace(name: &str) -> Workspace {
+    let workspace = Workspace::new(name);
+    workspace.write("com/example/base/BaseController.java", BASE_CONTROLLER);
+    workspace.write("com/example/web/UserController.java", USER_CONTROLLER);
+    workspace.write("com/example/web/ApiPaths.java", API_PATHS);
+    workspace
+}
+
+#[test]
+fn test_cached_scan_returns_same_endpoints() {
+    let workspace = create_workspace("same");
+
+    let first = workspace.scan();
+    assert!(workspace.cache_dir().join("scan-cache.json").exists());
+    let second = workspace.scan();
+
+    assert_eq!(summary(&first), summary(&second));
+    assert_eq!(
+        summary(&second),
+        vec![
+            "GET /users/{id} UserController#getUser",
+            "GET /users/health BaseController#health",
+        ]
+    );
+}
+
+#[test]
+fn test_unchanged_file_is_not_parsed_again() {
+    let workspace = create_workspace("unchanged");
+    workspace.scan();
+
+    // キャッシュの内容を書き換えて、ファイルを再パースせずにキャッシュを使っていることを確認する
+    let mut cache = workspace.read_cache();
+    let key = workspace.cache_key("com/example/web/UserController.java");
+    cache["entries"][&key]["endpoints"]["endpoints"][0]["method_name"] =
+        serde_json::Value::from("fromCache");
+    workspace.write_cache(&cache);
+
+    let endpoints = workspace.scan();
+    assert_eq!(endpoints[0].method_name, "fromCache");
+
+    // ファイルを変更すると再パースされる
+    workspace.write(
+        "com/example/web/UserController.java",
+        &USER_CONTROLLER.replace("getUser", "findUser"),
+    );
+    let endpoints = workspace.scan();
+    assert_eq!(endpoints[0].method_name, "findUser");
+}
+
+#[test]
+fn test_changed_parent_class_updates_inherited_endpoints() {
+    let workspace = create_workspace("parent");
+    workspace.scan();
+
+    // 子クラスのファイルは変わらなくても、親クラスの変更は継承したエンドポイントに反映される
+    workspace.write(
+        "com/example/base/BaseController.java",
+        &BASE_CONTROLLER.replace("/health", "/status"),
+    );
+
+    assert_eq!(
+        summary(&workspace.scan()),
+        vec![
+            "GET /users/{id} UserController#getUser",
+            "GET /users/status BaseController#health",
+        ]
+    );
+}
+
+#[test]
+fn test_changed_constant_updates_endpoints() {
+    let workspace = create_workspace("constant");
+    workspace.scan();
+
+    workspace.write(
+        "com/example/web/ApiPaths.java",
+        &API_PATHS.replace("/users", "/members"),
+    );
+
+    assert_eq!(
+        summary(&workspace.scan()),
+        vec![
+            "GET /members/{id} UserController#getUser",
+            "GET /members/health BaseController#health",
+        ]
+    );
+}
+
+#[test]
+fn test_new_class_changes_parent_resolution() {
+    let workspace = Workspace::new("resolution");
+    workspace.write("com/example/base/BaseController.java", BASE_CONTROLLER);
+    // import宣言なしで参照しているため、最初は別パッケージの同名クラスには解決されない
+    // （継承されていない別パッケージのBaseControllerは単独のコントローラーとして扱う）
+    workspace.write(
+        "com/example/web/UserController.java",
+        &USER_CONTROLLER
+            .replace("import com.example.base.BaseController;\n", "")
+            .replace("ApiPaths.USERS", "\"/users\""),
+    );
+    assert_eq!(
+        summary(&workspace.scan()),
+        vec![
+            "GET /health BaseController#health",
+            "GET /users/{id} UserController#getUser",
+        ]
+    );
+
+    // 同じパッケージにクラスが追加されると、そちらに解決し直す
+    // （別パッケージのBaseControllerは引き続き継承されていないものとして扱う）
+    workspace.write(
+        "com/example/web/BaseController.java",
+        &BASE_CONTROLLER
+            .replace("com.example.base", "com.example.web")
+            .replace("/health", "/ping"),
+    );
+    assert_eq!(
+        summary(&workspace.scan()),
+        vec![
+            "GET /health BaseController#health",
+            "GET /users/{id} UserController#getUser",
+            "GET /users/ping BaseController#health",
+        ]
+    );
+}
+
+#[test]
+fn test_removed_file_is_dropped_from_cache() {
+    let workspace = create_workspace("removed");
+    workspace.scan();
+    let key = workspace.cache_key("com/example/web/ApiPaths.java");
+    assert!(workspace.read_cache()["entries"].get(&key).is_some());
+
+    workspace.remove("com/example/web/ApiPaths.java");
+    workspace.scan();
+    assert!(workspace.read_cache()["entries"].get(&key).is_none());
+}
+
+#[test]
+fn test_broken_cache_is_ignored() {
+    let workspace = create_workspace("broken");
+    let expected = summary(&workspace.scan());
+
+    fs::write(workspace.cache_dir().join("scan-cache.json"), "{ broken").unwrap();
+
+    assert_eq!(summary(&workspace.scan()), expected);
+}
@@ -1,10 +1,13 @@
+mod common;
+
 use std::collections::HashSet;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::condition;
     use anyhow::Result;
-    use path_finder::{scan_directory, Endpoint, Parameter};
+    use path_finder::{scan_directory, ConditionOperator, Endpoint, Parameter};
 
     #[test]
     fn test_scan_directory() -> Result<()> {
@@ -23,20 +26,29 @@ mod tests {
         let expected_endpoints = vec![
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getAllUsers".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/users".to_string(),
+                canonical_path: "/api/users".to_string(),
                 parameters: vec![],
                 line_range: (24, 27),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUserById".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/users/{id}".to_string(),
+                canonical_path: "/api/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "id".to_string(),
                     param_type: "Long".to_string(),
@@ -44,14 +56,20 @@ mod tests {
                 }],
                 line_range: (29, 32),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUserById2".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/users/{id}".to_string(),
+                canonical_path: "/api/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "id".to_string(),
                     param_type: "Long".to_string(),
@@ -59,14 +77,20 @@ mod tests {
                 }],
                 line_range: (34, 37),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "createUser".to_string(),
                 http_method: "POST".to_string(),
                 path: "/api/users".to_string(),
+                canonical_path: "/api/users".to_string(),
                 parameters: vec![Parameter {
                     name: "user".to_string(),
                     param_type: "User".to_string(),
@@ -74,14 +98,20 @@ mod tests {
                 }],
                 line_range: (39, 42),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "createUser2".to_string(),
                 http_method: "POST".to_string(),
                 path: "/api/users/{id}".to_string(),
+                canonical_path: "/api/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "user".to_string(),
                     param_type: "User".to_string(),
@@ -89,25 +119,37 @@ mod tests {
                 }],
                 line_range: (44, 47),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "XCustomHeader".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getAllUsers".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/kotlin/users".to_string(),
+                canonical_path: "/api/kotlin/users".to_string(),
                 parameters: vec![],
                 line_range: (19, 22),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUserById".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/kotlin/users/{id}".to_string(),
+                canonical_path: "/api/kotlin/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "id".to_string(),
                     param_type: "Long".to_string(),
@@ -115,14 +157,20 @@ mod tests {
                 }],
                 line_range: (24, 27),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUserById2".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/kotlin/users/{id}".to_string(),
+                canonical_path: "/api/kotlin/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "id".to_string(),
                     param_type: "Long".to_string(),
@@ -130,14 +178,20 @@ mod tests {
                 }],
                 line_range: (29, 32),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUserById3".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/kotlin/users/{id}".to_string(),
+                canonical_path: "/api/kotlin/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "id".to_string(),
                     param_type: "Long".to_string(),
@@ -145,14 +199,20 @@ mod tests {
                 }],
                 line_range: (34, 37),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "XCustomHeader".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "createUser".to_string(),
                 http_method: "POST".to_string(),
                 path: "/api/kotlin/users".to_string(),
+                canonical_path: "/api/kotlin/users".to_string(),
                 parameters: vec![Parameter {
                     name: "user".to_string(),
                     param_type: "User".to_string(),
@@ -160,14 +220,20 @@ mod tests {
                 }],
                 line_range: (39, 42),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "createUser2".to_string(),
                 http_method: "POST".to_string(),
                 path: "/api/kotlin/users/{id}".to_string(),
+                canonical_path: "/api/kotlin/users/{id}".to_string(),
                 parameters: vec![Parameter {
                     name: "user".to_string(),
                     param_type: "User".to_string(),
@@ -175,25 +241,26 @@ mod tests {
                 }],
                 line_range: (44, 47),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "updateNameId".to_string(),
                 http_method: "PUT".to_string(),
                 path: "/api/kotlin/users/{id}/name-id".to_string(),
+                canonical_path: "/api/kotlin/users/{id}/name-id".to_string(),
                 parameters: vec![
                     Parameter {
                         name: "id".to_string(),
                         param_type: "Long".to_string(),
                         annotation: "PathVariable".to_string(),
                     },
-                    Parameter {
-                        name: "params".to_string(),
-                        param_type: "UpdateNameIdRequestParams".to_string(),
-                        annotation: "PathVariable".to_string(),
-                    },
                     Parameter {
                         name: "params".to_string(),
                         param_type: "UpdateNameIdRequestParams".to_string(),
@@ -202,54 +269,87 @@ mod tests {
                 ],
                 line_range: (49, 52),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "".to_string(),
+                headers: vec![],
+                params: vec![],
+                consumes: vec![],
+                produces: vec!["application/json".to_string()],
+                module: None,
             },
             // Java params付きエンドポイント
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUsersV1".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/users".to_string(),
+                canonical_path: "/api/users".to_string(),
                 parameters: vec![],
                 line_range: (49, 52),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "".to_string(),
-                params: "\"version=1\"".to_string(),
+                headers: vec![],
+                params: vec![condition("version", ConditionOperator::Equals, Some("1"))],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "searchUsers".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/users/search".to_string(),
+                canonical_path: "/api/users/search".to_string(),
                 parameters: vec![],
                 line_range: (54, 57),
                 file_path: "tests/resources/UserController.java".to_string(),
-                headers: "".to_string(),
-                params: "{\"q\", \"type=advanced\"}".to_string(),
+                headers: vec![],
+                params: vec![
+                    condition("q", ConditionOperator::Present, None),
+                    condition("type", ConditionOperator::Equals, Some("advanced")),
+                ],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             // Kotlin params付きエンドポイント
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "getUsersV2".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/kotlin/users".to_string(),
+                canonical_path: "/api/kotlin/users".to_string(),
                 parameters: vec![],
                 line_range: (54, 57),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "\"version=2\"".to_string(),
+                headers: vec![],
+                params: vec![condition("version", ConditionOperator::Equals, Some("2"))],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
             Endpoint {
                 class_name: "UserController".to_string(),
+                package: Some("com.example.demo.controller".to_string()),
+                qualified_class_name: "com.example.demo.controller.UserController".to_string(),
                 method_name: "searchKotlinUsers".to_string(),
                 http_method: "GET".to_string(),
                 path: "/api/kotlin/users/search".to_string(),
+                canonical_path: "/api/kotlin/users/search".to_string(),
                 parameters: vec![],
                 line_range: (59, 62),
                 file_path: "tests/resources/UserController.kt".to_string(),
-                headers: "".to_string(),
-                params: "\"type=kotlin\"".to_string(),
+                headers: vec![],
+                params: vec![
+                    condition("q", ConditionOperator::Present, None),
+                    condition("type", ConditionOperator::Equals, Some("kotlin")),
+                ],
+                consumes: vec![],
+                produces: vec![],
+                module: None,
             },
         ];
 
@@ -268,6 +368,8 @@ mod tests {
     // エンドポイントの比較関数
     fn endpoints_match(a: &Endpoint, b: &Endpoint) -> bool {
         if a.class_name != b.class_name
+            || a.package != b.package
+            || a.qualified_class_name != b.qualified_class_name
             || a.method_name != b.method_name
             || a.http_method != b.http_method
             || a.path != b.path
@@ -317,7 +419,7 @@ mod tests {
         for endpoint in &endpoints {
             if !endpoint.params.is_empty() {
                 println!(
-                    "  {} {} - params: {}",
+                    "  {} {} - params: {:?}",
                     endpoint.http_method, endpoint.path, endpoint.params
                 );
             }
@@ -333,7 +435,8 @@ mod tests {
         );
         if let Some(endpoint) = java_v1_endpoint {
             assert_eq!(
-                endpoint.params, "\"version=1\"",
+                endpoint.params,
+                vec![condition("version", ConditionOperator::Equals, Some("1"))],
                 "Java getUsersV1のparams値が正しくありません"
             );
         }
@@ -347,7 +450,11 @@ mod tests {
         );
         if let Some(endpoint) = java_search_endpoint {
             assert_eq!(
-                endpoint.params, "{\"q\", \"type=advanced\"}",
+                endpoint.params,
+                vec![
+                    condition("q", ConditionOperator::Present, None),
+                    condition("type", ConditionOperator::Equals, Some("advanced")),
+                ],
                 "Java searchUsersのparams値が正しくありません"
             );
         }
@@ -362,7 +469,8 @@ mod tests {
         );
         if let Some(endpoint) = kotlin_v2_endpoint {
             assert_eq!(
-                endpoint.params, "\"version=2\"",
+                endpoint.params,
+                vec![condition("version", ConditionOperator::Equals, Some("2"))],
                 "Kotlin getUsersV2のparams値が正しくありません"
             );
         }
@@ -376,7 +484,11 @@ mod tests {
         );
         if let Some(endpoint) = kotlin_search_endpoint {
             assert_eq!(
-                endpoint.params, "\"type=kotlin\"",
+                endpoint.params,
+                vec![
+                    condition("q", ConditionOperator::Present, None),
+                    condition("type", ConditionOperator::Equals, Some("kotlin")),
+                ],
                 "Kotlin searchKotlinUsersのparams値が正しくありません"
             );
         }
@@ -390,9 +502,9 @@ mod tests {
             "params無しエンドポイントが見つかりません"
         );
         if let Some(endpoint) = no_params_endpoint {
-            assert_eq!(
-                endpoint.params, "",
-                "params無しエンドポイントのparams値は空文字列であるべきです"
+            assert!(
+                endpoint.params.is_empty(),
+                "params無しエンドポイントのparams値は空であるべきです"
             );
         }
 
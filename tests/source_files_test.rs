@@ -0,0 +1,154 @@
+use path_finder::diagnostics::DiagnosticKind;
+use path_finder::{scan_directory_report_with_options, ScanOptions};
+
+const RESOURCES: &str = "tests/resources_source_files";
+
+// (パス, ファイルのルートからの相対パス) の一覧
+fn scanned_endpoints(dir_path: &str, options: &ScanOptions) -> Vec<(String, String)> {
+    let report = scan_directory_report_with_options(dir_path, options).unwrap();
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+    report
+        .endpoints
+        .iter()
+        .map(|endpoint| {
+            (
+                endpoint.path.clone(),
+                endpoint
+                    .file_path
+                    .strip_prefix(dir_path)
+                    .unwrap()
+                    .trim_start_matches('/')
+                    .to_string(),
+            )
+        })
+        .collect()
+}
+
+fn endpoint(path: &str, file_path: &str) -> (String, String) {
+    (path.to_string(), file_path.to_string())
+}
+
+#[test]
+fn test_build_output_and_tool_dirs_are_excluded_by_default() {
+    // ビルドの出力先のコピーは除外するが、buildという名前のパッケージは除外しない
+    assert_eq!(
+        scanned_endpoints(RESOURCES, &ScanOptions::default()),
+        vec![
+            endpoint(
+                "/users",
+                "src/main/java/com/example/api/UserController.java"
+            ),
+            endpoint(
+                "/builds",
+                "src/main/java/com/example/build/BuildController.java"
+            ),
+            endpoint(
+                "/ignored",
+                "src/main/java/com/example/ignored/IgnoredController.java"
+            ),
+        ]
+    );
+
+    let options = ScanOptions {
+        default_excludes: false,
+        ..ScanOptions::default()
+    };
+    assert_eq!(
+        scanned_endpoints(RESOURCES, &options),
+        vec![
+            endpoint(
+                "/users",
+                "build/generated/com/example/api/UserController.java"
+            ),
+            endpoint("/sample", "node_modules/sample/SampleController.java"),
+            endpoint(
+                "/users",
+                "src/main/java/com/example/api/UserController.java"
+            ),
+            endpoint(
+                "/builds",
+                "src/main/java/com/example/build/BuildController.java"
+            ),
+            endpoint(
+                "/ignored",
+                "src/main/java/com/example/ignored/IgnoredController.java"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_gitignore() {
+    let options = ScanOptions {
+        gitignore: true,
+        ..ScanOptions::default()
+    };
+    assert_eq!(
+        scanned_endpoints(RESOURCES, &options),
+        vec![
+            endpoint(
+                "/users",
+                "src/main/java/com/example/api/UserController.java"
+            ),
+            endpoint(
+                "/builds",
+                "src/main/java/com/example/build/BuildController.java"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_include_and_exclude() {
+    let options = ScanOptions {
+        include: vec!["src/main/java/**".to_string()],
+        exclude: vec!["ignored".to_string(), "*Build*.java".to_string()],
+        default_excludes: false,
+        ..ScanOptions::default()
+    };
+    assert_eq!(
+        scanned_endpoints(RESOURCES, &options),
+        vec![endpoint(
+            "/users",
+            "src/main/java/com/example/api/UserController.java"
+        )]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let dir = std::env::temp_dir().join(format!(
+        "path-finder-source-files-test-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    let package_dir = dir.join("com/example/api");
+    std::fs::create_dir_all(&package_dir).unwrap();
+    std::fs::copy(
+        format!(
+            "{}/src/main/java/com/example/api/UserController.java",
+            RESOURCES
+        ),
+        package_dir.join("UserController.java"),
+    )
+    .unwrap();
+    // 別のディレクトリを指すリンク（同じファイルに2つのパスから辿れる）と、祖先を指すリンク（循環）
+    symlink(&package_dir, dir.join("api-link")).unwrap();
+    symlink(&dir, package_dir.join("loop")).unwrap();
+
+    let dir_path = dir.to_string_lossy().to_string();
+    let report = scan_directory_report_with_options(&dir_path, &ScanOptions::default()).unwrap();
+
+    assert_eq!(report.endpoints.len(), 1);
+    assert!(report.endpoints[0]
+        .file_path
+        .ends_with("api-link/UserController.java"));
+    assert_eq!(report.diagnostics.len(), 1, "{:?}", report.diagnostics);
+    assert_eq!(report.diagnostics[0].kind, DiagnosticKind::SymlinkCycle);
+    assert!(report.diagnostics[0].file_path.ends_with("loop"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}